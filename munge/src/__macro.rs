@@ -1,6 +1,14 @@
-use core::{hint::unreachable_unchecked, marker::PhantomData};
+use core::{
+    hint::unreachable_unchecked,
+    marker::PhantomData,
+    mem::{ManuallyDrop, MaybeUninit},
+};
 
-use crate::{internal, Borrow, Destructure, Restructure};
+use crate::{
+    internal, Aliasable, Borrow, Destructure, DiscriminantStable, Emplace,
+    Finish, ProjectUnions, Restructure, RestructureRaw, RestructureRef, Shared,
+    Slot, Transparent, Uninit, Written, Zeroable,
+};
 
 pub fn make_destructurer<T: Destructure>(
     value: T,
@@ -11,6 +19,51 @@ where
     internal::Destructurer::new(value)
 }
 
+pub struct Probe<T>(PhantomData<fn() -> T>);
+
+impl<T> Probe<T> {
+    pub fn new(_: &T) -> Self {
+        Self(PhantomData)
+    }
+}
+
+pub struct Nest;
+
+impl Nest {
+    pub fn apply<T>(self, value: T) -> internal::Nested<T> {
+        internal::Nested::new(value)
+    }
+}
+
+pub struct Keep;
+
+impl Keep {
+    pub fn apply<T>(self, value: T) -> T {
+        value
+    }
+}
+
+pub trait PeelNested {
+    fn kind(&self) -> Nest {
+        Nest
+    }
+}
+
+impl<T> PeelNested for Probe<T>
+where
+    T: Destructure,
+    T::Underlying: Transparent,
+{
+}
+
+pub trait PeelValue {
+    fn kind(&self) -> Keep {
+        Keep
+    }
+}
+
+impl<T> PeelValue for &Probe<T> {}
+
 pub fn destructurer_ptr<T: internal::Destructurer>(
     destructurer: &mut T,
 ) -> *mut <T::Inner as Destructure>::Underlying {
@@ -31,13 +84,14 @@ pub unsafe fn test_destructurer<'a, T: internal::Test<'a>>(
 ///
 /// `ptr` must be a properly-aligned pointer to a subfield of the pointer
 /// underlying the inner value of `destructurer`.
-pub unsafe fn restructure_destructurer<T: internal::Destructurer, U>(
+pub unsafe fn restructure_destructurer<T: internal::Destructurer, U: ?Sized>(
     destructurer: &T,
     ptr: *mut U,
 ) -> <T::Inner as Restructure<U>>::Restructured
 where
     T::Inner: Restructure<U>,
 {
+    internal::Destructurer::restructuring(destructurer);
     // SAFETY: The caller has guaranteed that `ptr` is a properly-aligned
     // pointer to a subfield of the pointer underlying the inner value of
     // `destructurer`.
@@ -49,6 +103,248 @@ where
     }
 }
 
+/// # Safety
+///
+/// `ptr` must be a properly-aligned pointer to a subfield of the pointer
+/// underlying the inner value of `destructurer`.
+pub unsafe fn restructure_destructurer_ref<T, U>(
+    destructurer: &T,
+    ptr: *mut U,
+) -> <T::Inner as RestructureRef<U>>::RestructuredRef
+where
+    T: internal::Destructurer,
+    T::Inner: RestructureRef<U>,
+    U: ?Sized,
+{
+    internal::Destructurer::restructuring(destructurer);
+    // SAFETY: The caller has guaranteed that `ptr` is a properly-aligned
+    // pointer to a subfield of the pointer underlying the inner value of
+    // `destructurer`.
+    unsafe {
+        RestructureRef::restructure_ref(
+            internal::Destructurer::inner(destructurer),
+            ptr,
+        )
+    }
+}
+
+/// # Safety
+///
+/// `ptr` must be a properly-aligned pointer to a subfield of the pointer
+/// underlying the inner value of `destructurer`.
+pub unsafe fn restructure_destructurer_raw<T, U>(
+    destructurer: &T,
+    ptr: *mut U,
+) -> <T::Inner as RestructureRaw<U>>::RestructuredRaw
+where
+    T: internal::Destructurer,
+    T::Inner: RestructureRaw<U>,
+    U: ?Sized,
+{
+    internal::Destructurer::restructuring(destructurer);
+    // SAFETY: The caller has guaranteed that `ptr` is a properly-aligned
+    // pointer to a subfield of the pointer underlying the inner value of
+    // `destructurer`.
+    unsafe {
+        RestructureRaw::restructure_raw(
+            internal::Destructurer::inner(destructurer),
+            ptr,
+        )
+    }
+}
+
+/// # Safety
+///
+/// `ptr` must be a properly-aligned pointer to the `index`th subfield
+/// restructured from the pointer underlying the inner value of `destructurer`,
+/// and fields must be visited in the same order that they are restructured.
+pub unsafe fn drop_unrestructured<T: internal::Destructurer, U: ?Sized>(
+    destructurer: &T,
+    ptr: *mut U,
+    index: &mut usize,
+) where
+    T::Inner: Restructure<U>,
+{
+    // SAFETY: The caller has guaranteed that `ptr` is a properly-aligned
+    // pointer to the `index`th subfield restructured from the pointer
+    // underlying the inner value of `destructurer`.
+    unsafe { destructurer.drop_unrestructured(ptr, index) }
+}
+
+pub struct UnwindGuard<F: FnMut()>(F);
+
+impl<F: FnMut()> UnwindGuard<F> {
+    pub fn new(f: F) -> Self {
+        Self(f)
+    }
+
+    pub fn disarm(self) {
+        core::mem::forget(self);
+    }
+}
+
+impl<F: FnMut()> Drop for UnwindGuard<F> {
+    fn drop(&mut self) {
+        (self.0)();
+    }
+}
+
+pub fn check_project_unions<T>(_: &T)
+where
+    T: internal::Destructurer,
+    T::Inner: ProjectUnions,
+{
+}
+
+#[diagnostic::on_unimplemented(
+    message = "munge may only emplace enum variants into `&mut MaybeUninit`",
+    label = "`{Self}` is not a `&mut MaybeUninit`"
+)]
+pub trait EmplaceInto {}
+
+impl<T> EmplaceInto for &mut MaybeUninit<T> {}
+
+pub fn check_emplace<T>(_: &T)
+where
+    T: internal::Destructurer,
+    T::Inner: EmplaceInto,
+    <T::Inner as Destructure>::Underlying: Emplace,
+{
+}
+
+pub fn check_discriminant_stable<T>(_: &T)
+where
+    T: internal::Destructurer,
+    T::Inner: DiscriminantStable,
+{
+}
+
+pub fn zero_fill<T: Zeroable>(value: &mut MaybeUninit<T>) {
+    // SAFETY: `value` is a valid pointer to a single `T`.
+    unsafe { value.as_mut_ptr().write_bytes(0, 1) }
+}
+
+pub struct ConstDestructurer<T: ?Sized>(*mut T);
+
+impl<T: ?Sized> ConstDestructurer<T> {
+    pub const fn new(ptr: *mut T) -> Self {
+        Self(ptr)
+    }
+}
+
+impl<T> ConstDestructurer<MaybeUninit<T>> {
+    pub const fn underlying(&self) -> *mut T {
+        self.0.cast()
+    }
+
+    pub const fn restructure<U>(&self, ptr: *mut U) -> *mut MaybeUninit<U> {
+        ptr.cast()
+    }
+}
+
+impl<T> ConstDestructurer<ManuallyDrop<T>> {
+    pub const fn underlying(&self) -> *mut T {
+        self.0.cast()
+    }
+
+    pub const fn restructure<U>(&self, ptr: *mut U) -> *mut ManuallyDrop<U> {
+        ptr.cast()
+    }
+}
+
+pub fn test_uninit<'brand, T>(_: &Finish<'brand, '_, T>) -> Uninit<'brand, T> {
+    unreachable!()
+}
+
+pub fn check_written<'brand, T>(_: Slot<'brand, T>, _: &Written<'brand, T>) {}
+
+/// # Safety
+///
+/// Every field of the storage that `finish` was created for must have been
+/// written.
+pub unsafe fn finish_unchecked<'a, T>(finish: Finish<'_, 'a, T>) -> &'a mut T {
+    // SAFETY: The caller has guaranteed that every field of the storage has
+    // been written.
+    unsafe { finish.finish_unchecked() }
+}
+
+pub fn test_const_destructurer<'a, T: ?Sized>(
+    _: &ConstDestructurer<T>,
+) -> &'a mut T {
+    unreachable!()
+}
+
+pub const fn const_array_len<T, const N: usize>(_: *mut [T; N]) -> usize {
+    N
+}
+
+pub struct AccessProbe<T>(PhantomData<fn() -> T>);
+
+pub fn access_probe<T: internal::Destructurer>(_: &T) -> AccessProbe<T::Inner> {
+    AccessProbe(PhantomData)
+}
+
+pub trait StableAccess {
+    type Access;
+
+    fn access(&self) -> PhantomData<Self::Access> {
+        PhantomData
+    }
+}
+
+impl<T: DiscriminantStable> StableAccess for AccessProbe<T> {
+    type Access = T::Access;
+}
+
+// Only used after `check_discriminant_stable` fails, so that the failure
+// doesn't cause more errors.
+pub trait UnstableAccess {
+    fn access(&self) -> PhantomData<Shared> {
+        PhantomData
+    }
+}
+
+impl<T> UnstableAccess for &AccessProbe<T> {}
+
+/// # Safety
+///
+/// `ptr` must be a properly-aligned pointer to a subfield of the pointer
+/// underlying a `DiscriminantStable` value with access `A`.
+pub unsafe fn access<'a, A: internal::Access, T: ?Sized>(
+    _: PhantomData<A>,
+    ptr: *mut T,
+) -> A::Ref<'a, T> {
+    // SAFETY: The caller has guaranteed that `ptr` points to a subfield of a
+    // `DiscriminantStable` value, which may be accessed with `A`.
+    unsafe { A::access(ptr) }
+}
+
+pub trait FieldPtr {
+    type Field: ?Sized;
+
+    fn field_ptr(self) -> *mut Self::Field;
+}
+
+impl<T: ?Sized> FieldPtr for &T {
+    type Field = T;
+
+    fn field_ptr(self) -> *mut Self::Field {
+        (self as *const T).cast_mut()
+    }
+}
+
+impl<T: ?Sized> FieldPtr for &mut T {
+    type Field = T;
+
+    fn field_ptr(self) -> *mut Self::Field {
+        self as *mut T
+    }
+}
+
+pub fn field_ptr<T: FieldPtr>(field: T) -> *mut T::Field {
+    field.field_ptr()
+}
+
 #[diagnostic::on_unimplemented(
     message = "munge may not destructure a rest pattern by move",
     label = "this rest pattern requires destructuring by borrow"
@@ -58,6 +354,305 @@ pub trait MustBeBorrow {}
 #[diagnostic::do_not_recommend]
 impl MustBeBorrow for Borrow {}
 
+/// The fields of a value which are named by a pattern with a rest pattern.
+#[derive(Clone, Copy)]
+pub enum Named<'a> {
+    /// The fields named by a struct pattern, like `Example { a, b, .. }`.
+    Fields(&'a [&'a str]),
+    /// The number of elements before and after the rest pattern of a tuple,
+    /// tuple struct, or slice pattern, like `Example(a, .., b)`.
+    Elements(usize, usize),
+}
+
+impl Named<'_> {
+    /// Returns whether the field with the given name and index is named, out
+    /// of `len` fields.
+    pub fn contains(self, name: &str, index: usize, len: usize) -> bool {
+        match self {
+            Self::Fields(names) => names.contains(&name),
+            Self::Elements(front, back) => index < front || index + back >= len,
+        }
+    }
+}
+
+#[diagnostic::on_unimplemented(
+    message = "munge may not destructure a rest pattern of `{T}` by move",
+    label = "this rest pattern requires destructuring by borrow",
+    note = "derive `DropRest` on `{T}` to drop the fields it doesn't name"
+)]
+pub trait RestPattern<T: ?Sized> {
+    /// Restructures and drops the fields of the value that `ptr` points to
+    /// which are not in `named`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a properly-aligned pointer to a subfield of the pointer
+    /// underlying the inner value, and the fields which are not in `named`
+    /// must not have been restructured.
+    unsafe fn drop_rest(&self, ptr: *mut T, named: Named<'_>);
+
+    /// Restructures and drops the fields of the value that `ptr` points to
+    /// which are not in `named` if they have not already been dropped, then
+    /// advances `index` to the next field.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a properly-aligned pointer to the `index`th subfield
+    /// restructured from the pointer underlying the inner value, and each
+    /// field must be visited in the same order that it was restructured.
+    unsafe fn drop_unrestructured_rest(
+        &self,
+        ptr: *mut T,
+        named: Named<'_>,
+        index: &mut usize,
+    );
+}
+
+/// Restructures and drops a field of a `DropRest` type if it is not named.
+///
+/// # Safety
+///
+/// `ptr` must be a properly-aligned pointer to a subfield of the pointer
+/// underlying `restructurer`, and must not have been restructured if it is not
+/// named.
+pub unsafe fn drop_field<T: Restructure<U>, U: ?Sized>(
+    restructurer: &T,
+    ptr: *mut U,
+    named: Named<'_>,
+    name: &str,
+    index: usize,
+    len: usize,
+) {
+    if !named.contains(name, index, len) {
+        // SAFETY: The caller has guaranteed that `ptr` is a properly-aligned
+        // pointer to a subfield of the pointer underlying `restructurer`
+        // which has not been restructured.
+        drop(unsafe { restructurer.restructure(ptr) });
+    }
+}
+
+pub fn check_aliasable<T>(_: &T)
+where
+    T: internal::Destructurer,
+    T::Inner: Aliasable,
+{
+}
+
+pub struct Attributed<T> {
+    pub value: T,
+}
+
+#[diagnostic::on_unimplemented(
+    message = "munge may only destructure arrays with slice patterns",
+    label = "this slice pattern requires an array"
+)]
+pub trait TestArray {
+    type Element;
+
+    fn test_slice(&self) -> &[Self::Element];
+}
+
+impl<T, const N: usize> TestArray for [T; N] {
+    type Element = T;
+
+    fn test_slice(&self) -> &[Self::Element] {
+        self
+    }
+}
+
+impl<T> TestArray for [T] {
+    type Element = T;
+
+    fn test_slice(&self) -> &[Self::Element] {
+        self
+    }
+}
+
+impl<T: TestArray + ?Sized> TestArray for &T {
+    type Element = T::Element;
+
+    fn test_slice(&self) -> &[Self::Element] {
+        T::test_slice(self)
+    }
+}
+
+pub fn test_array<T: TestArray + ?Sized>(value: &T) -> &[T::Element] {
+    value.test_slice()
+}
+
+pub struct ArrayLen<const LEN: usize, const EXACT: bool>;
+
+pub trait CheckLength<T: ?Sized> {
+    fn check(self, ptr: *mut T) -> usize;
+}
+
+impl<T, const N: usize, const LEN: usize, const EXACT: bool> CheckLength<[T; N]>
+    for ArrayLen<LEN, EXACT>
+{
+    fn check(self, _: *mut [T; N]) -> usize {
+        const {
+            if EXACT {
+                assert!(N == LEN, "munge slice pattern has the wrong length");
+            } else {
+                assert!(N >= LEN, "munge slice pattern is too long for array");
+            }
+        }
+        N
+    }
+}
+
+impl<T, const LEN: usize, const EXACT: bool> CheckLength<[T]>
+    for ArrayLen<LEN, EXACT>
+{
+    fn check(self, ptr: *mut [T]) -> usize {
+        let len = ptr.len();
+        if EXACT {
+            assert!(len == LEN, "munge slice pattern has the wrong length");
+        } else {
+            assert!(len >= LEN, "munge slice pattern is too long for slice");
+        }
+        len
+    }
+}
+
+pub trait SliceLen {
+    fn slice_len(ptr: *mut Self) -> usize;
+}
+
+impl<T, const N: usize> SliceLen for [T; N] {
+    fn slice_len(_: *mut Self) -> usize {
+        N
+    }
+}
+
+impl<T> SliceLen for [T] {
+    fn slice_len(ptr: *mut Self) -> usize {
+        ptr.len()
+    }
+}
+
+pub fn slice_len<T: SliceLen + ?Sized>(ptr: *mut T) -> usize {
+    T::slice_len(ptr)
+}
+
+#[diagnostic::on_unimplemented(
+    message = "munge may only destructure arrays into columns",
+    label = "`{Self}` is not an array or a reference to an array or slice"
+)]
+pub trait IntoRows<T, const N: usize> {
+    #[track_caller]
+    fn into_rows(self) -> [T; N];
+}
+
+impl<T, const N: usize> IntoRows<T, N> for [T; N] {
+    fn into_rows(self) -> [T; N] {
+        self
+    }
+}
+
+impl<'a, T, const N: usize> IntoRows<&'a T, N> for &'a [T; N] {
+    fn into_rows(self) -> [&'a T; N] {
+        self.each_ref()
+    }
+}
+
+impl<'a, T, const N: usize> IntoRows<&'a mut T, N> for &'a mut [T; N] {
+    fn into_rows(self) -> [&'a mut T; N] {
+        self.each_mut()
+    }
+}
+
+#[track_caller]
+fn slice_len_mismatch(expected: usize, len: usize) -> ! {
+    panic!(
+        "munge may only destructure a slice of length {expected} into \
+         columns, but the slice has length {len}",
+    )
+}
+
+impl<'a, T, const N: usize> IntoRows<&'a T, N> for &'a [T] {
+    fn into_rows(self) -> [&'a T; N] {
+        match <&[T; N]>::try_from(self) {
+            Ok(array) => array.each_ref(),
+            Err(_) => slice_len_mismatch(N, self.len()),
+        }
+    }
+}
+
+impl<'a, T, const N: usize> IntoRows<&'a mut T, N> for &'a mut [T] {
+    fn into_rows(self) -> [&'a mut T; N] {
+        let len = self.len();
+        match <&mut [T; N]>::try_from(self) {
+            Ok(array) => array.each_mut(),
+            Err(_) => slice_len_mismatch(N, len),
+        }
+    }
+}
+
+#[track_caller]
+pub fn into_rows<T, const N: usize, U: IntoRows<T, N>>(value: U) -> [T; N] {
+    value.into_rows()
+}
+
+/// # Safety
+///
+/// `field` must return a pointer to a field of the given row. Each field
+/// returned by `field` must not be read again or dropped after calling
+/// `take_column`.
+pub unsafe fn take_column<T, U, const N: usize>(
+    rows: &[T; N],
+    field: impl Fn(&T) -> *const U,
+) -> [U; N] {
+    // SAFETY: The caller has guaranteed that `field` returns a pointer to a
+    // field of the row, and that the field will not be read again or dropped.
+    core::array::from_fn(|i| unsafe { field(&rows[i]).read() })
+}
+
+#[diagnostic::on_unimplemented(
+    message = "munge may only destructure values, `Option`s, and `Result`s",
+    label = "`{Self}` can't be destructured"
+)]
+pub trait Lift {
+    type Inner;
+    type Output<R>;
+
+    fn lift<R>(self, f: impl FnOnce(Self::Inner) -> R) -> Self::Output<R>;
+}
+
+impl<T: Destructure> Lift for T {
+    type Inner = T;
+    type Output<R> = R;
+
+    fn lift<R>(self, f: impl FnOnce(Self::Inner) -> R) -> Self::Output<R> {
+        f(self)
+    }
+}
+
+impl<T> Lift for Option<T> {
+    type Inner = T;
+    type Output<R> = Option<R>;
+
+    fn lift<R>(self, f: impl FnOnce(Self::Inner) -> R) -> Self::Output<R> {
+        self.map(f)
+    }
+}
+
+impl<T, E> Lift for Result<T, E> {
+    type Inner = T;
+    type Output<R> = Result<R, E>;
+
+    fn lift<R>(self, f: impl FnOnce(Self::Inner) -> R) -> Self::Output<R> {
+        self.map(f)
+    }
+}
+
+pub fn lift<T: Lift, R>(
+    value: T,
+    f: impl FnOnce(T::Inner) -> R,
+) -> T::Output<R> {
+    value.lift(f)
+}
+
 pub fn get_destructuring_ptr<T>(
     _: &T,
 ) -> *const <T::Inner as Destructure>::Destructuring
@@ -1,10 +1,17 @@
 use core::{
     cell::{Cell, UnsafeCell},
+    marker::PhantomData,
     mem::{transmute, ManuallyDrop, MaybeUninit},
-    ptr::read,
+    num::Wrapping,
+    pin::Pin,
+    ptr::{read, NonNull},
 };
 
-use crate::{Borrow, Destructure, Move, Restructure};
+use crate::{
+    Aliasable, Borrow, Destructure, DiscriminantStable, Exclusive, Move,
+    ProjectUnions, Restructure, RestructureRaw, RestructureRef, Shared,
+    Transparent, Zeroable,
+};
 
 // MaybeUninit<T>
 
@@ -36,6 +43,18 @@ unsafe impl<T, U> Restructure<U> for MaybeUninit<T> {
     }
 }
 
+// SAFETY: `MaybeUninit<T>` is `repr(transparent)` and so has the same layout
+// as `T`. Any field of an uninitialized `T` may be accessed as a
+// `MaybeUninit<U>`.
+unsafe impl<T> Transparent for MaybeUninit<T> {
+    type Inner = T;
+    type Wrapped<U> = MaybeUninit<U>;
+}
+
+// SAFETY: `MaybeUninit<T>` never assumes that its contents are initialized, so
+// any field of a union may be restructured from it.
+unsafe impl<T> ProjectUnions for MaybeUninit<T> {}
+
 // &MaybeUninit<T>
 
 // SAFETY:
@@ -66,6 +85,35 @@ unsafe impl<'a, T, U: 'a> Restructure<U> for &'a MaybeUninit<T> {
     }
 }
 
+// SAFETY: `restructure_ref` returns the same shared borrow as `restructure`.
+unsafe impl<'a, T, U: 'a> RestructureRef<U> for &'a MaybeUninit<T> {
+    type RestructuredRef = &'a MaybeUninit<U>;
+
+    unsafe fn restructure_ref(&self, ptr: *mut U) -> Self::RestructuredRef {
+        // SAFETY: The caller has guaranteed that `ptr` is a properly aligned
+        // pointer to a subfield of the pointer underlying `self`.
+        unsafe { self.restructure(ptr) }
+    }
+}
+
+// SAFETY: `restructure_raw` casts `ptr` to a pointer to the same field wrapped
+// in `MaybeUninit<U>`.
+unsafe impl<'a, T, U: 'a> RestructureRaw<U> for &'a MaybeUninit<T> {
+    type RestructuredRaw = *const MaybeUninit<U>;
+
+    unsafe fn restructure_raw(&self, ptr: *mut U) -> Self::RestructuredRaw {
+        ptr.cast_const().cast()
+    }
+}
+
+// SAFETY: `&MaybeUninit<T>` restructures fields as shared borrows,
+// which may alias each other.
+unsafe impl<T> Aliasable for &MaybeUninit<T> {}
+
+// SAFETY: `&MaybeUninit<T>` never assumes that its contents are initialized,
+// so any field of a union may be restructured from it.
+unsafe impl<T> ProjectUnions for &MaybeUninit<T> {}
+
 // &mut MaybeUninit<T>
 
 // SAFETY:
@@ -96,6 +144,32 @@ unsafe impl<'a, T, U: 'a> Restructure<U> for &'a mut MaybeUninit<T> {
     }
 }
 
+// SAFETY: `restructure_ref` reborrows the disjoint borrow returned by
+// `restructure` as a shared borrow.
+unsafe impl<'a, T, U: 'a> RestructureRef<U> for &'a mut MaybeUninit<T> {
+    type RestructuredRef = &'a MaybeUninit<U>;
+
+    unsafe fn restructure_ref(&self, ptr: *mut U) -> Self::RestructuredRef {
+        // SAFETY: The caller has guaranteed that `ptr` is a properly aligned
+        // pointer to a subfield of the pointer underlying `self`.
+        unsafe { self.restructure(ptr) }
+    }
+}
+
+// SAFETY: `restructure_raw` casts `ptr` to a pointer to the same field wrapped
+// in `MaybeUninit<U>`.
+unsafe impl<'a, T, U: 'a> RestructureRaw<U> for &'a mut MaybeUninit<T> {
+    type RestructuredRaw = *mut MaybeUninit<U>;
+
+    unsafe fn restructure_raw(&self, ptr: *mut U) -> Self::RestructuredRaw {
+        ptr.cast()
+    }
+}
+
+// SAFETY: `&mut MaybeUninit<T>` never assumes that its contents are
+// initialized, so any field of a union may be restructured from it.
+unsafe impl<T> ProjectUnions for &mut MaybeUninit<T> {}
+
 // Cell<T>
 
 // SAFETY:
@@ -125,6 +199,13 @@ unsafe impl<T, U> Restructure<U> for Cell<T> {
     }
 }
 
+// SAFETY: `Cell<T>` is `repr(transparent)` and so has the same layout as `T`.
+// Any field of a `T` in a `Cell` may be accessed as a `Cell<U>`.
+unsafe impl<T> Transparent for Cell<T> {
+    type Inner = T;
+    type Wrapped<U> = Cell<U>;
+}
+
 // &Cell<T>
 
 // SAFETY:
@@ -160,6 +241,44 @@ unsafe impl<'a, T: ?Sized, U: 'a + ?Sized> Restructure<U> for &'a Cell<T> {
     }
 }
 
+// SAFETY: `restructure_ref` returns the same shared borrow as `restructure`.
+unsafe impl<'a, T, U> RestructureRef<U> for &'a Cell<T>
+where
+    T: ?Sized,
+    U: 'a + ?Sized,
+{
+    type RestructuredRef = &'a Cell<U>;
+
+    unsafe fn restructure_ref(&self, ptr: *mut U) -> Self::RestructuredRef {
+        // SAFETY: The caller has guaranteed that `ptr` is a properly aligned
+        // pointer to a subfield of the pointer underlying `self`.
+        unsafe { self.restructure(ptr) }
+    }
+}
+
+// SAFETY: `restructure_raw` casts `ptr` to a pointer to the same field wrapped
+// in `Cell<U>`.
+unsafe impl<'a, T, U> RestructureRaw<U> for &'a Cell<T>
+where
+    T: ?Sized,
+    U: 'a + ?Sized,
+{
+    type RestructuredRaw = *const Cell<U>;
+
+    unsafe fn restructure_raw(&self, ptr: *mut U) -> Self::RestructuredRaw {
+        // SAFETY: `Cell<U>` is `repr(transparent)` and so guaranteed to
+        // have the same representation as the `U` it contains. Therefore, the
+        // pointer metadata for `*const Cell<U>` is the same as the
+        // metadata for `*mut U`, and transmuting between the two types is
+        // sound.
+        unsafe { transmute::<*mut U, *const Cell<U>>(ptr) }
+    }
+}
+
+// SAFETY: `&Cell<T>` restructures fields as shared borrows,
+// which may alias each other.
+unsafe impl<T: ?Sized> Aliasable for &Cell<T> {}
+
 // &mut Cell<T>
 
 // SAFETY:
@@ -195,6 +314,47 @@ unsafe impl<'a, T: ?Sized, U: 'a + ?Sized> Restructure<U> for &'a mut Cell<T> {
     }
 }
 
+// SAFETY: `restructure_ref` reborrows the disjoint borrow returned by
+// `restructure` as a shared borrow.
+unsafe impl<'a, T, U> RestructureRef<U> for &'a mut Cell<T>
+where
+    T: ?Sized,
+    U: 'a + ?Sized,
+{
+    type RestructuredRef = &'a Cell<U>;
+
+    unsafe fn restructure_ref(&self, ptr: *mut U) -> Self::RestructuredRef {
+        // SAFETY: The caller has guaranteed that `ptr` is a properly aligned
+        // pointer to a subfield of the pointer underlying `self`.
+        unsafe { self.restructure(ptr) }
+    }
+}
+
+// SAFETY: `restructure_raw` casts `ptr` to a pointer to the same field wrapped
+// in `Cell<U>`.
+unsafe impl<'a, T, U> RestructureRaw<U> for &'a mut Cell<T>
+where
+    T: ?Sized,
+    U: 'a + ?Sized,
+{
+    type RestructuredRaw = *mut Cell<U>;
+
+    unsafe fn restructure_raw(&self, ptr: *mut U) -> Self::RestructuredRaw {
+        // SAFETY: `Cell<U>` is `repr(transparent)` and so guaranteed to
+        // have the same representation as the `U` it contains. Therefore, the
+        // pointer metadata for `*mut Cell<U>` is the same as the
+        // metadata for `*mut U`, and transmuting between the two types is
+        // sound.
+        unsafe { transmute::<*mut U, *mut Cell<U>>(ptr) }
+    }
+}
+
+// SAFETY: `&mut Cell<T>` has exclusive access to its initialized underlying
+// value, so the value can't be modified while it is borrowed.
+unsafe impl<T: ?Sized> DiscriminantStable for &mut Cell<T> {
+    type Access = Exclusive;
+}
+
 // UnsafeCell<T>
 
 // SAFETY:
@@ -225,6 +385,14 @@ unsafe impl<T, U> Restructure<U> for UnsafeCell<T> {
     }
 }
 
+// SAFETY: `UnsafeCell<T>` is `repr(transparent)` and so has the same layout as
+// `T`. Any field of a `T` in an `UnsafeCell` may be accessed as an
+// `UnsafeCell<U>`.
+unsafe impl<T> Transparent for UnsafeCell<T> {
+    type Inner = T;
+    type Wrapped<U> = UnsafeCell<U>;
+}
+
 // &UnsafeCell<T>
 
 // SAFETY:
@@ -265,6 +433,44 @@ where
     }
 }
 
+// SAFETY: `restructure_ref` returns the same shared borrow as `restructure`.
+unsafe impl<'a, T, U> RestructureRef<U> for &'a UnsafeCell<T>
+where
+    T: ?Sized,
+    U: 'a + ?Sized,
+{
+    type RestructuredRef = &'a UnsafeCell<U>;
+
+    unsafe fn restructure_ref(&self, ptr: *mut U) -> Self::RestructuredRef {
+        // SAFETY: The caller has guaranteed that `ptr` is a properly aligned
+        // pointer to a subfield of the pointer underlying `self`.
+        unsafe { self.restructure(ptr) }
+    }
+}
+
+// SAFETY: `restructure_raw` casts `ptr` to a pointer to the same field wrapped
+// in `UnsafeCell<U>`.
+unsafe impl<'a, T, U> RestructureRaw<U> for &'a UnsafeCell<T>
+where
+    T: ?Sized,
+    U: 'a + ?Sized,
+{
+    type RestructuredRaw = *const UnsafeCell<U>;
+
+    unsafe fn restructure_raw(&self, ptr: *mut U) -> Self::RestructuredRaw {
+        // SAFETY: `UnsafeCell<U>` is `repr(transparent)` and so guaranteed to
+        // have the same representation as the `U` it contains. Therefore, the
+        // pointer metadata for `*const UnsafeCell<U>` is the same as the
+        // metadata for `*mut U`, and transmuting between the two types is
+        // sound.
+        unsafe { transmute::<*mut U, *const UnsafeCell<U>>(ptr) }
+    }
+}
+
+// SAFETY: `&UnsafeCell<T>` restructures fields as shared borrows,
+// which may alias each other.
+unsafe impl<T: ?Sized> Aliasable for &UnsafeCell<T> {}
+
 // &mut UnsafeCell<T>
 
 // SAFETY:
@@ -304,6 +510,47 @@ where
     }
 }
 
+// SAFETY: `restructure_ref` reborrows the disjoint borrow returned by
+// `restructure` as a shared borrow.
+unsafe impl<'a, T, U> RestructureRef<U> for &'a mut UnsafeCell<T>
+where
+    T: ?Sized,
+    U: 'a + ?Sized,
+{
+    type RestructuredRef = &'a UnsafeCell<U>;
+
+    unsafe fn restructure_ref(&self, ptr: *mut U) -> Self::RestructuredRef {
+        // SAFETY: The caller has guaranteed that `ptr` is a properly aligned
+        // pointer to a subfield of the pointer underlying `self`.
+        unsafe { self.restructure(ptr) }
+    }
+}
+
+// SAFETY: `restructure_raw` casts `ptr` to a pointer to the same field wrapped
+// in `UnsafeCell<U>`.
+unsafe impl<'a, T, U> RestructureRaw<U> for &'a mut UnsafeCell<T>
+where
+    T: ?Sized,
+    U: 'a + ?Sized,
+{
+    type RestructuredRaw = *mut UnsafeCell<U>;
+
+    unsafe fn restructure_raw(&self, ptr: *mut U) -> Self::RestructuredRaw {
+        // SAFETY: `UnsafeCell<U>` is `repr(transparent)` and so guaranteed to
+        // have the same representation as the `U` it contains. Therefore, the
+        // pointer metadata for `*mut UnsafeCell<U>` is the same as the
+        // metadata for `*mut U`, and transmuting between the two types is
+        // sound.
+        unsafe { transmute::<*mut U, *mut UnsafeCell<U>>(ptr) }
+    }
+}
+
+// SAFETY: `&mut UnsafeCell<T>` has exclusive access to its initialized
+// underlying value, so the value can't be modified while it is borrowed.
+unsafe impl<T: ?Sized> DiscriminantStable for &mut UnsafeCell<T> {
+    type Access = Exclusive;
+}
+
 // ManuallyDrop<T>
 
 // SAFETY:
@@ -334,6 +581,20 @@ unsafe impl<T, U> Restructure<U> for ManuallyDrop<T> {
     }
 }
 
+// SAFETY: `ManuallyDrop<T>` is `repr(transparent)` and so has the same layout
+// as `T`. Any field of a `T` in a `ManuallyDrop` may be accessed as a
+// `ManuallyDrop<U>`.
+unsafe impl<T> Transparent for ManuallyDrop<T> {
+    type Inner = T;
+    type Wrapped<U> = ManuallyDrop<U>;
+}
+
+// SAFETY: `ManuallyDrop<T>` owns its initialized underlying value, so it may be
+// accessed exclusively.
+unsafe impl<T> DiscriminantStable for ManuallyDrop<T> {
+    type Access = Exclusive;
+}
+
 // &ManuallyDrop<T>
 
 // SAFETY:
@@ -374,6 +635,51 @@ where
     }
 }
 
+// SAFETY: `restructure_ref` returns the same shared borrow as `restructure`.
+unsafe impl<'a, T, U> RestructureRef<U> for &'a ManuallyDrop<T>
+where
+    T: ?Sized,
+    U: 'a + ?Sized,
+{
+    type RestructuredRef = &'a ManuallyDrop<U>;
+
+    unsafe fn restructure_ref(&self, ptr: *mut U) -> Self::RestructuredRef {
+        // SAFETY: The caller has guaranteed that `ptr` is a properly aligned
+        // pointer to a subfield of the pointer underlying `self`.
+        unsafe { self.restructure(ptr) }
+    }
+}
+
+// SAFETY: `restructure_raw` casts `ptr` to a pointer to the same field wrapped
+// in `ManuallyDrop<U>`.
+unsafe impl<'a, T, U> RestructureRaw<U> for &'a ManuallyDrop<T>
+where
+    T: ?Sized,
+    U: 'a + ?Sized,
+{
+    type RestructuredRaw = *const ManuallyDrop<U>;
+
+    unsafe fn restructure_raw(&self, ptr: *mut U) -> Self::RestructuredRaw {
+        // SAFETY: `ManuallyDrop<U>` is `repr(transparent)` and so guaranteed to
+        // have the same representation as the `U` it contains. Therefore, the
+        // pointer metadata for `*const ManuallyDrop<U>` is the same as the
+        // metadata for `*mut U`, and transmuting between the two types is
+        // sound.
+        unsafe { transmute::<*mut U, *const ManuallyDrop<U>>(ptr) }
+    }
+}
+
+// SAFETY: `&ManuallyDrop<T>` restructures fields as shared borrows,
+// which may alias each other.
+unsafe impl<T: ?Sized> Aliasable for &ManuallyDrop<T> {}
+
+// SAFETY: `&ManuallyDrop<T>` has shared access to its initialized underlying
+// value. Reading its discriminant through a shared reference is the same as
+// matching on any other shared reference.
+unsafe impl<T: ?Sized> DiscriminantStable for &ManuallyDrop<T> {
+    type Access = Shared;
+}
+
 // &mut ManuallyDrop<T>
 
 // SAFETY:
@@ -414,3 +720,237 @@ where
         unsafe { &mut *ptr }
     }
 }
+
+// SAFETY: `restructure_ref` reborrows the disjoint borrow returned by
+// `restructure` as a shared borrow.
+unsafe impl<'a, T, U> RestructureRef<U> for &'a mut ManuallyDrop<T>
+where
+    T: ?Sized,
+    U: 'a + ?Sized,
+{
+    type RestructuredRef = &'a ManuallyDrop<U>;
+
+    unsafe fn restructure_ref(&self, ptr: *mut U) -> Self::RestructuredRef {
+        // SAFETY: The caller has guaranteed that `ptr` is a properly aligned
+        // pointer to a subfield of the pointer underlying `self`.
+        unsafe { self.restructure(ptr) }
+    }
+}
+
+// SAFETY: `restructure_raw` casts `ptr` to a pointer to the same field wrapped
+// in `ManuallyDrop<U>`.
+unsafe impl<'a, T, U> RestructureRaw<U> for &'a mut ManuallyDrop<T>
+where
+    T: ?Sized,
+    U: 'a + ?Sized,
+{
+    type RestructuredRaw = *mut ManuallyDrop<U>;
+
+    unsafe fn restructure_raw(&self, ptr: *mut U) -> Self::RestructuredRaw {
+        // SAFETY: `ManuallyDrop<U>` is `repr(transparent)` and so guaranteed to
+        // have the same representation as the `U` it contains. Therefore, the
+        // pointer metadata for `*mut ManuallyDrop<U>` is the same as the
+        // metadata for `*mut U`, and transmuting between the two types is
+        // sound.
+        unsafe { transmute::<*mut U, *mut ManuallyDrop<U>>(ptr) }
+    }
+}
+
+// SAFETY: `&mut ManuallyDrop<T>` has exclusive access to its initialized
+// underlying value, so the value can't be modified while it is borrowed.
+unsafe impl<T: ?Sized> DiscriminantStable for &mut ManuallyDrop<T> {
+    type Access = Exclusive;
+}
+
+// Pin<&ManuallyDrop<T>>
+
+// SAFETY:
+// - `Pin<&ManuallyDrop<T>>` is destructured by borrow, so its `Destructuring`
+//   type is `Borrow`.
+// - `underlying` returns a pointer to its inner type, so it is guaranteed to be
+//   non-null, properly aligned, and valid for reads.
+unsafe impl<T: ?Sized> Destructure for Pin<&ManuallyDrop<T>> {
+    type Underlying = T;
+    type Destructuring = Borrow;
+
+    fn underlying(&mut self) -> *mut Self::Underlying {
+        (&***self as *const Self::Underlying).cast_mut()
+    }
+}
+
+// SAFETY: `restructure` returns a `Pin<&ManuallyDrop<U>>` that borrows the
+// restructured field because `Pin<&ManuallyDrop<T>>` is destructured by borrow.
+unsafe impl<'a, T, U> Restructure<U> for Pin<&'a ManuallyDrop<T>>
+where
+    T: ?Sized,
+    U: 'a + ?Sized + Unpin,
+{
+    type Restructured = Pin<&'a ManuallyDrop<U>>;
+
+    unsafe fn restructure(&self, ptr: *mut U) -> Self::Restructured {
+        // SAFETY: `ManuallyDrop<U>` is `repr(transparent)` and so guaranteed to
+        // have the same representation as the `U` it contains. Therefore, the
+        // pointer metadata for `*const ManuallyDrop<U>` is the same as the
+        // metadata for `*mut U`, and transmuting between the two types is
+        // sound.
+        let ptr = unsafe { transmute::<*mut U, *const ManuallyDrop<U>>(ptr) };
+        // SAFETY: The caller has guaranteed that `ptr` points to a subfield of
+        // some `ManuallyDrop<T>`, so it's safe to dereference. Because the
+        // destructuring type for `Pin<&ManuallyDrop<T>>` is `Borrow`, we may
+        // create a disjoint borrow of it for `'a`. `T` may not structurally pin
+        // its fields, so only `Unpin` fields are restructured as pinned.
+        Pin::new(unsafe { &*ptr })
+    }
+}
+
+// SAFETY: `Pin<&ManuallyDrop<T>>` has shared access to its initialized
+// underlying value. Reading its discriminant through a shared reference is the
+// same as matching on any other shared reference.
+unsafe impl<T: ?Sized> DiscriminantStable for Pin<&ManuallyDrop<T>> {
+    type Access = Shared;
+}
+
+// Pin<&mut ManuallyDrop<T>>
+
+// SAFETY:
+// - `Pin<&mut ManuallyDrop<T>>` is destructured by borrow, so its
+//   `Destructuring` type is `Borrow`.
+// - `underlying` returns a pointer to its inner type, so it is guaranteed to be
+//   non-null, properly aligned, and valid for reads.
+unsafe impl<T: ?Sized> Destructure for Pin<&mut ManuallyDrop<T>> {
+    type Underlying = T;
+    type Destructuring = Borrow;
+
+    fn underlying(&mut self) -> *mut Self::Underlying {
+        // SAFETY: The underlying value is never moved out of. Only `Unpin`
+        // fields may be restructured, and those may be moved freely.
+        let md = unsafe { self.as_mut().get_unchecked_mut() };
+        &mut **md as *mut Self::Underlying
+    }
+}
+
+// SAFETY: `restructure` returns a `Pin<&mut ManuallyDrop<U>>` that borrows the
+// restructured field because `Pin<&mut ManuallyDrop<T>>` is destructured by
+// borrow.
+unsafe impl<'a, T, U> Restructure<U> for Pin<&'a mut ManuallyDrop<T>>
+where
+    T: ?Sized,
+    U: 'a + ?Sized + Unpin,
+{
+    type Restructured = Pin<&'a mut ManuallyDrop<U>>;
+
+    unsafe fn restructure(&self, ptr: *mut U) -> Self::Restructured {
+        // SAFETY: `ManuallyDrop<U>` is `repr(transparent)` and so guaranteed to
+        // have the same representation as the `U` it contains. Therefore, the
+        // pointer metadata for `*mut ManuallyDrop<U>` is the same as the
+        // metadata for `*mut U`, and transmuting between the two types is
+        // sound.
+        let ptr = unsafe { transmute::<*mut U, *mut ManuallyDrop<U>>(ptr) };
+        // SAFETY: The caller has guaranteed that `ptr` points to a subfield of
+        // some `ManuallyDrop<T>`, so it's safe to dereference. Because the
+        // destructuring type for `Pin<&mut ManuallyDrop<T>>` is `Borrow`, we
+        // may create a disjoint borrow of it for `'a`. `T` may not structurally
+        // pin its fields, so only `Unpin` fields are restructured as pinned.
+        Pin::new(unsafe { &mut *ptr })
+    }
+}
+
+// SAFETY: `Pin<&mut ManuallyDrop<T>>` has exclusive access to its initialized
+// underlying value, so the value can't be modified while it is borrowed.
+unsafe impl<T: ?Sized> DiscriminantStable for Pin<&mut ManuallyDrop<T>> {
+    type Access = Exclusive;
+}
+
+// Zeroable
+
+macro_rules! impl_zeroable {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            // SAFETY: All-zero bytes are a valid value of this type.
+            unsafe impl Zeroable for $ty {}
+        )*
+    };
+}
+
+impl_zeroable!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+);
+
+// SAFETY: All-zero bytes are a null pointer. Pointers to sized types have no
+// metadata, so a null pointer is a valid raw pointer.
+unsafe impl<T> Zeroable for *const T {}
+
+// SAFETY: All-zero bytes are a null pointer. Pointers to sized types have no
+// metadata, so a null pointer is a valid raw pointer.
+unsafe impl<T> Zeroable for *mut T {}
+
+// SAFETY: All-zero bytes are `None`, because `&T` has a null niche.
+unsafe impl<T> Zeroable for Option<&T> {}
+
+// SAFETY: All-zero bytes are `None`, because `&mut T` has a null niche.
+unsafe impl<T> Zeroable for Option<&mut T> {}
+
+// SAFETY: All-zero bytes are `None`, because `NonNull<T>` has a null niche.
+unsafe impl<T> Zeroable for Option<NonNull<T>> {}
+
+// SAFETY: `PhantomData<T>` is a zero-sized type with no invalid values.
+unsafe impl<T: ?Sized> Zeroable for PhantomData<T> {}
+
+// SAFETY: `MaybeUninit<T>` may hold any bytes.
+unsafe impl<T> Zeroable for MaybeUninit<T> {}
+
+// SAFETY: `ManuallyDrop<T>` is `repr(transparent)`, so it is valid when zeroed
+// if `T` is.
+unsafe impl<T: Zeroable + ?Sized> Zeroable for ManuallyDrop<T> {}
+
+// SAFETY: `Cell<T>` is `repr(transparent)`, so it is valid when zeroed if `T`
+// is.
+unsafe impl<T: Zeroable + ?Sized> Zeroable for Cell<T> {}
+
+// SAFETY: `UnsafeCell<T>` is `repr(transparent)`, so it is valid when zeroed
+// if `T` is.
+unsafe impl<T: Zeroable + ?Sized> Zeroable for UnsafeCell<T> {}
+
+// SAFETY: `Wrapping<T>` is `repr(transparent)`, so it is valid when zeroed if
+// `T` is.
+unsafe impl<T: Zeroable> Zeroable for Wrapping<T> {}
+
+// SAFETY: Every element of the array is valid when zeroed.
+unsafe impl<T: Zeroable, const N: usize> Zeroable for [T; N] {}
+
+macro_rules! impl_zeroable_tuple {
+    ($($ty:ident),*) => {
+        // SAFETY: Every element of the tuple is valid when zeroed, and padding
+        // bytes may have any value.
+        unsafe impl<$($ty: Zeroable),*> Zeroable for ($($ty,)*) {}
+    };
+}
+
+impl_zeroable_tuple!(A);
+impl_zeroable_tuple!(A, B);
+impl_zeroable_tuple!(A, B, C);
+impl_zeroable_tuple!(A, B, C, D);
+impl_zeroable_tuple!(A, B, C, D, E);
+impl_zeroable_tuple!(A, B, C, D, E, F);
+impl_zeroable_tuple!(A, B, C, D, E, F, G);
+impl_zeroable_tuple!(A, B, C, D, E, F, G, H);
+impl_zeroable_tuple!(A, B, C, D, E, F, G, H, I);
+impl_zeroable_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_zeroable_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_zeroable_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
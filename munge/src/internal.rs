@@ -1,6 +1,10 @@
-use core::mem::ManuallyDrop;
+use core::{cell::Cell, mem::ManuallyDrop};
 
-use crate::Destructure;
+use crate::{
+    __macro::{Named, RestPattern},
+    Destructure, DropRest, Restructure, RestructureRaw, RestructureRef,
+    Transparent,
+};
 
 pub trait Destructuring {}
 
@@ -16,6 +20,38 @@ pub trait Destructurer {
     fn inner(&self) -> &Self::Inner;
 
     fn inner_mut(&mut self) -> &mut Self::Inner;
+
+    /// Records that the next field is about to be restructured.
+    fn restructuring(&self) {}
+
+    /// Restructures and drops the `index`th field if it has not already been
+    /// restructured, then advances `index` to the next field.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a properly-aligned pointer to the `index`th subfield
+    /// restructured from the pointer underlying the inner value, and each
+    /// field must be visited in the same order that it was restructured.
+    unsafe fn drop_unrestructured<U: ?Sized>(
+        &self,
+        ptr: *mut U,
+        index: &mut usize,
+    ) where
+        Self::Inner: Restructure<U>,
+    {
+        let _ = ptr;
+        *index += 1;
+    }
+}
+
+pub trait Access {
+    type Ref<'a, T: 'a + ?Sized>;
+
+    /// # Safety
+    ///
+    /// `ptr` must be non-null, properly aligned, and valid for reads. If `Ref`
+    /// is a mutable reference, it must also be valid for writes.
+    unsafe fn access<'a, T: ?Sized>(ptr: *mut T) -> Self::Ref<'a, T>;
 }
 
 pub trait Test<'a> {
@@ -40,25 +76,98 @@ impl<T: Destructure> Destructurer for Borrow<T> {
     }
 }
 
+// Borrowed fields are left in place, so rest patterns don't drop anything.
+#[diagnostic::do_not_recommend]
+impl<T: Destructure, U: ?Sized> RestPattern<U> for Borrow<T> {
+    unsafe fn drop_rest(&self, _: *mut U, _: Named<'_>) {}
+
+    unsafe fn drop_unrestructured_rest(
+        &self,
+        _: *mut U,
+        _: Named<'_>,
+        index: &mut usize,
+    ) {
+        *index += 1;
+    }
+}
+
 impl<'a, T: 'a + Destructure> Test<'a> for Borrow<T> {
     type Test = &'a T::Underlying;
 }
 
-pub struct Move<T>(ManuallyDrop<T>);
+pub struct Move<T> {
+    inner: ManuallyDrop<T>,
+    restructured: Cell<usize>,
+}
 
 impl<T: Destructure> Destructurer for Move<T> {
     type Inner = T;
 
     fn new(inner: T) -> Self {
-        Self(ManuallyDrop::new(inner))
+        Self {
+            inner: ManuallyDrop::new(inner),
+            restructured: Cell::new(0),
+        }
     }
 
     fn inner(&self) -> &Self::Inner {
-        &self.0
+        &self.inner
     }
 
     fn inner_mut(&mut self) -> &mut Self::Inner {
-        &mut self.0
+        &mut self.inner
+    }
+
+    fn restructuring(&self) {
+        self.restructured.set(self.restructured.get() + 1);
+    }
+
+    unsafe fn drop_unrestructured<U: ?Sized>(
+        &self,
+        ptr: *mut U,
+        index: &mut usize,
+    ) where
+        Self::Inner: Restructure<U>,
+    {
+        // Fields are moved into `restructure` when it is called, so a field
+        // whose `restructure` panicked is not dropped again.
+        if *index >= self.restructured.get() {
+            // SAFETY: The caller has guaranteed that `ptr` is a
+            // properly-aligned pointer to a subfield of the pointer underlying
+            // the inner value. That field has not been restructured, so it
+            // still owns its value.
+            drop(unsafe { Restructure::restructure(self.inner(), ptr) });
+        }
+        *index += 1;
+    }
+}
+
+#[diagnostic::do_not_recommend]
+impl<T: Destructure, U: ?Sized + DropRest<T>> RestPattern<U> for Move<T> {
+    unsafe fn drop_rest(&self, ptr: *mut U, named: Named<'_>) {
+        // Dropping the rest of the fields counts as restructuring one field,
+        // so they aren't dropped again if dropping one of them panics.
+        self.restructuring();
+        // SAFETY: The caller has guaranteed that `ptr` is a properly-aligned
+        // pointer to a subfield of the pointer underlying the inner value, and
+        // that the fields which are not in `named` haven't been restructured.
+        unsafe { U::drop_rest(ptr, self.inner(), named) }
+    }
+
+    unsafe fn drop_unrestructured_rest(
+        &self,
+        ptr: *mut U,
+        named: Named<'_>,
+        index: &mut usize,
+    ) {
+        if *index >= self.restructured.get() {
+            // SAFETY: The caller has guaranteed that `ptr` is a
+            // properly-aligned pointer to a subfield of the pointer underlying
+            // the inner value. The rest of its fields haven't been dropped, so
+            // they still own their values.
+            unsafe { U::drop_rest(ptr, self.inner(), named) }
+        }
+        *index += 1;
     }
 }
 
@@ -68,3 +177,88 @@ where
 {
     type Test = T::Underlying;
 }
+
+pub struct Nested<T>(T);
+
+type NestedWrapped<T, U> =
+    <<T as Destructure>::Underlying as Transparent>::Wrapped<U>;
+
+impl<T> Nested<T> {
+    pub fn new(inner: T) -> Self {
+        Self(inner)
+    }
+}
+
+// SAFETY:
+// - `Nested<T>` is destructured the same way as `T`, so its `Destructuring`
+//   type is the same as `T`'s.
+// - `underlying` returns a pointer to the inner type of `T`'s underlying type.
+//   Because that underlying type is `Transparent`, the pointer has the same
+//   address and so is guaranteed to be non-null, properly aligned, and valid
+//   for reads.
+unsafe impl<T> Destructure for Nested<T>
+where
+    T: Destructure,
+    T::Underlying: Transparent,
+{
+    type Underlying = <T::Underlying as Transparent>::Inner;
+    type Destructuring = T::Destructuring;
+
+    fn underlying(&mut self) -> *mut Self::Underlying {
+        self.0.underlying().cast()
+    }
+}
+
+// SAFETY: `restructure` returns the value restructured by `T` with the field
+// wrapped in the same `Transparent` wrapper as `T`'s underlying type, so it
+// upholds the same invariants as `T`'s restructure.
+unsafe impl<T, U> Restructure<U> for Nested<T>
+where
+    T: Destructure + Restructure<NestedWrapped<T, U>>,
+    <T as Destructure>::Underlying: Transparent,
+{
+    type Restructured = T::Restructured;
+
+    unsafe fn restructure(&self, ptr: *mut U) -> Self::Restructured {
+        // SAFETY: `Wrapped<U>` has the same layout as `U`, so the caller's
+        // guarantee that `ptr` is a properly aligned pointer to a subfield of
+        // the pointer underlying `self` holds for the cast pointer as well.
+        unsafe { self.0.restructure(ptr.cast()) }
+    }
+}
+
+// SAFETY: `restructure_ref` returns the value restructured by `T` with the
+// field wrapped in the same `Transparent` wrapper as `T`'s underlying type, so
+// it upholds the same invariants as `T`'s `restructure_ref`.
+unsafe impl<T, U> RestructureRef<U> for Nested<T>
+where
+    T: Destructure + RestructureRef<NestedWrapped<T, U>>,
+    <T as Destructure>::Underlying: Transparent,
+{
+    type RestructuredRef = T::RestructuredRef;
+
+    unsafe fn restructure_ref(&self, ptr: *mut U) -> Self::RestructuredRef {
+        // SAFETY: `Wrapped<U>` has the same layout as `U`, so the caller's
+        // guarantee that `ptr` is a properly aligned pointer to a subfield of
+        // the pointer underlying `self` holds for the cast pointer as well.
+        unsafe { self.0.restructure_ref(ptr.cast()) }
+    }
+}
+
+// SAFETY: `restructure_raw` returns the pointer restructured by `T` with the
+// field wrapped in the same `Transparent` wrapper as `T`'s underlying type, so
+// it points to the same field as `T`'s `restructure_raw`.
+unsafe impl<T, U> RestructureRaw<U> for Nested<T>
+where
+    T: Destructure + RestructureRaw<NestedWrapped<T, U>>,
+    <T as Destructure>::Underlying: Transparent,
+{
+    type RestructuredRaw = T::RestructuredRaw;
+
+    unsafe fn restructure_raw(&self, ptr: *mut U) -> Self::RestructuredRaw {
+        // SAFETY: `Wrapped<U>` has the same layout as `U`, so the caller's
+        // guarantee that `ptr` is a properly aligned pointer to a subfield of
+        // the pointer underlying `self` holds for the cast pointer as well.
+        unsafe { self.0.restructure_raw(ptr.cast()) }
+    }
+}
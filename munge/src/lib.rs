@@ -7,7 +7,8 @@
 //! destructuring (e.g. `let (a, b) = c` where `c` is a value) depending on the
 //! type.
 //!
-//! Munge has no features and is always `#![no_std]`.
+//! Munge has no features and is always `#![no_std]`. It requires Rust 1.85 or
+//! later.
 //!
 //! ## Examples
 #![doc = include_str!("../example.md")]
@@ -31,17 +32,84 @@
 pub mod __macro;
 mod impls;
 mod internal;
+mod slots;
 
+#[doc(hidden)]
+pub use munge_macro::finish_with_path;
+#[doc(hidden)]
+pub use munge_macro::init_with_path;
+#[doc(hidden)]
+pub use munge_macro::munge_const_with_path;
 #[doc(hidden)]
 pub use munge_macro::munge_with_path;
+/// Destructures the parameters of a function using munge.
+///
+/// Each parameter of the function marked with `#[munge]` is replaced with
+/// a plain parameter of the same type, which is destructured with
+/// [`munge!`] at the start of the function body. Closure parameters inside
+/// of the function may be destructured the same way by marking them with
+/// `#[munge]`. Other parameters are left as they are, so they may still
+/// use ordinary patterns.
+///
+/// This attribute is named `params` instead of `munge` because attribute
+/// macros share a namespace with function-like macros, so it would
+/// conflict with [`munge!`].
+///
+/// The path to the munge crate defaults to `::munge`, and may be changed
+/// with `#[params(crate = path::to::munge)]`.
+///
+/// # Example
+///
+/// ```
+/// # use core::mem::MaybeUninit;
+/// pub struct Config {
+///     a: u32,
+///     b: (char, f32),
+/// }
+///
+/// #[munge::params]
+/// fn init(#[munge] Config { a, b: (c, f) }: &mut MaybeUninit<Config>) {
+///     a.write(10);
+///     c.write('x');
+///     f.write(3.14);
+/// }
+///
+/// #[munge::params]
+/// fn init_all(configs: &mut [MaybeUninit<Config>]) {
+///     configs.iter_mut().for_each(
+///         |#[munge] Config { a, b }: &mut MaybeUninit<Config>| {
+///             a.write(1);
+///             b.write(('y', 1.41));
+///         },
+///     );
+/// }
+///
+/// let mut mu = MaybeUninit::<Config>::uninit();
+/// init(&mut mu);
+/// // SAFETY: `mu` is completely initialized.
+/// let init = unsafe { mu.assume_init() };
+/// assert_eq!(init.a, 10);
+/// ```
+pub use munge_macro::params;
+/// Derives [`DropRest`] for a struct.
+///
+/// The path to the munge crate defaults to `::munge`, and may be changed
+/// with `#[munge(crate = path::to::munge)]`.
+pub use munge_macro::DropRest;
+/// Derives [`Emplace`] for an enum with a primitive representation.
+///
+/// The path to the munge crate defaults to `::munge`, and may be changed
+/// with `#[munge(crate = path::to::munge)]`.
+pub use munge_macro::Emplace;
+
+pub use self::slots::{slots, Finish, Slot, Uninit, Written};
 
 /// Destructures a type using a pattern.
 ///
-/// To prevent unsound union destructurings, this macro emits field accesses
-/// which fail to compile in safe Rust. However, if `munge!` is used inside of
-/// an `unsafe` block, these accesses will compile without emitting an error.
-/// This matches the behavior of regular destructuring, but may be surprising in
-/// some situations.
+/// To prevent unsound union destructurings, this macro rejects struct patterns
+/// on unions whether or not it is used inside of an `unsafe` block. Union
+/// fields may only be destructured through [`ProjectUnions`] types by marking
+/// them with `#[union]`.
 ///
 /// # Example
 ///
@@ -69,11 +137,405 @@ pub use munge_macro::munge_with_path;
 /// assert_eq!(init.b.0, 'x');
 /// assert_eq!(init.b.1, 3.14);
 /// ```
+///
+/// # Tuples of values
+///
+/// A tuple expression destructured with a tuple pattern is split into
+/// independent destructures, one for each element. Each element may have a
+/// different destructurable type:
+///
+/// ```
+/// # use core::{cell::Cell, mem::MaybeUninit};
+/// # use munge::munge;
+/// let mut mu = MaybeUninit::<(u32, char)>::uninit();
+/// let cell = Cell::new([1, 2]);
+///
+/// munge!(let ((a, b), [c, _]) = (&mut mu, &cell));
+/// a.write(c.get());
+/// b.write('x');
+/// ```
+///
+/// # Binding modes
+///
+/// Fields bound with `ref` are restructured as shared borrows with
+/// [`RestructureRef`], and fields bound with `raw!(name)` are restructured as
+/// raw pointers with [`RestructureRaw`]. This lets an exclusive borrow hand out
+/// a different kind of access for each field:
+///
+/// ```
+/// # use core::mem::MaybeUninit;
+/// # use munge::munge;
+/// let mut mu = MaybeUninit::new((1u32, 'x', 2.0f32));
+///
+/// munge!(let (a, ref b, raw!(c)) = &mut mu);
+/// let _: &mut MaybeUninit<u32> = a;
+/// let _: &MaybeUninit<char> = b;
+/// let _: *mut MaybeUninit<f32> = c;
+/// ```
+///
+/// # Type ascriptions
+///
+/// A destructure may ascribe a type to its pattern, like
+/// `let (a, b): (u32, char) = &mut mu`. The type is checked against the
+/// underlying type being destructured, not against the type of the expression.
+///
+/// # Paths
+///
+/// Path patterns like `Self` or `Kind::Empty` match unit structs and variants
+/// without binding them. A single identifier like `Empty` always introduces a
+/// binding, so unit structs must be written with a path or as `Empty {}`.
+/// Refutable patterns are different: like `None`, an identifier which starts
+/// with an uppercase letter names a unit struct, unit variant, or constant.
+///
+/// # Rest patterns
+///
+/// Irrefutable tuple and tuple struct patterns may only end with a rest
+/// pattern, like `(a, b, ..)`. Fields after the rest must be skipped with `_`
+/// instead. Refutable tuple struct patterns like `Some(Pair(a, .., c))` may
+/// have a rest pattern anywhere.
+///
+/// # Attributes
+///
+/// Attributes on field patterns and on whole `let` destructures are applied to
+/// the code which projects those fields. A field or destructure removed by
+/// `#[cfg]` introduces no bindings and doesn't access the destructured value:
+///
+/// ```
+/// # use core::mem::MaybeUninit;
+/// # use munge::munge;
+/// pub struct Example {
+///     a: u32,
+///     #[cfg(any())]
+///     b: char,
+/// }
+///
+/// let mut mu = MaybeUninit::<Example>::uninit();
+///
+/// munge!(let Example { a, #[cfg(any())] b } = &mut mu);
+/// a.write(1);
+/// ```
+///
+/// # Unions
+///
+/// Types which implement [`ProjectUnions`], like `&mut MaybeUninit`, may
+/// destructure union fields marked with `#[union]`:
+///
+/// ```
+/// # use core::mem::MaybeUninit;
+/// # use munge::munge;
+/// #[repr(C)]
+/// pub union Value {
+///     int: u64,
+///     float: f64,
+/// }
+///
+/// let mut mu = MaybeUninit::<Value>::uninit();
+///
+/// munge!(let Value { #[union] float } = &mut mu);
+/// float.write(1.5);
+///
+/// // SAFETY: `float` was initialized above.
+/// assert_eq!(unsafe { mu.assume_init().float }, 1.5);
+/// ```
+///
+/// # Emplacing enum variants
+///
+/// Enums which derive [`Emplace`] may have a variant emplaced into a
+/// `&mut MaybeUninit` with `let Enum::Variant { .. } = emplace expr`. This
+/// writes the discriminant of the variant and destructures its payload fields:
+///
+/// ```
+/// # use core::mem::MaybeUninit;
+/// # use munge::{munge, Emplace};
+/// #[derive(Emplace)]
+/// #[repr(C, u8)]
+/// pub enum Message {
+///     Quit,
+///     Move { x: i32, y: i32 },
+///     Write(char),
+/// }
+///
+/// let mut mu = MaybeUninit::<Message>::uninit();
+///
+/// munge!(let Message::Move { x, y } = emplace &mut mu);
+/// x.write(1);
+/// y.write(2);
+///
+/// // SAFETY: The discriminant and all of the fields of `Message::Move` have
+/// // been initialized.
+/// let message = unsafe { mu.assume_init() };
+/// assert!(matches!(message, Message::Move { x: 1, y: 2 }));
+/// ```
+///
+/// # Columns
+///
+/// An array of destructurable values can be destructured element-wise with the
+/// pattern `[pat; len]`, where `len` may be `_` to infer the length. Each
+/// binding in `pat` is collected into an array with one restructured value per
+/// element:
+///
+/// ```
+/// # use core::mem::MaybeUninit;
+/// # use munge::munge;
+/// pub struct Particle {
+///     pos: (f32, f32),
+///     vel: (f32, f32),
+/// }
+///
+/// let mut particles = [const { MaybeUninit::<Particle>::uninit() }; 4];
+///
+/// munge!(let [Particle { pos, vel }; _] = &mut particles);
+/// let _: [&mut MaybeUninit<(f32, f32)>; 4] = pos;
+/// for vel in vel {
+///     vel.write((0.0, 0.0));
+/// }
+/// ```
+///
+/// A reference to a slice can be destructured the same way, but its length
+/// can't be inferred and must be given explicitly. Destructuring panics if the
+/// slice doesn't have exactly `len` elements:
+///
+/// ```
+/// # use core::cell::Cell;
+/// # use munge::munge;
+/// # pub struct Particle {
+/// #     pos: (f32, f32),
+/// #     vel: (f32, f32),
+/// # }
+/// fn stop(particles: &[Cell<Particle>]) {
+///     munge!(let [Particle { vel, .. }; 2] = particles);
+///     for vel in vel {
+///         vel.set((0.0, 0.0));
+///     }
+/// }
+/// ```
+///
+/// # Expressions
+///
+/// `munge!(expr => pat)` destructures `expr` with `pat` and evaluates to a
+/// tuple of the bindings in `pat`, in the order they appear. Similarly, `let
+/// name = expr => pat` binds that tuple to `name`:
+///
+/// ```
+/// # use core::mem::MaybeUninit;
+/// # use munge::munge;
+/// pub struct Example {
+///     a: u32,
+///     b: (char, f32),
+/// }
+///
+/// fn fields(
+///     mu: &mut MaybeUninit<Example>,
+/// ) -> (&mut MaybeUninit<u32>, &mut MaybeUninit<char>) {
+///     munge!(mu => Example { a, b: (c, _) })
+/// }
+///
+/// let mut mu = MaybeUninit::<Example>::uninit();
+/// let (a, c) = fields(&mut mu);
+/// a.write(10);
+/// c.write('x');
+/// ```
+///
+/// # Options and results
+///
+/// When a destructurable value is inside of an `Option` or `Result`, the
+/// expression form evaluates to the same kind of container holding the tuple of
+/// bindings:
+///
+/// ```
+/// # use core::cell::Cell;
+/// # use munge::munge;
+/// pub struct State {
+///     count: u32,
+///     flag: bool,
+/// }
+///
+/// fn find(cell: &Cell<State>) -> Result<&Cell<State>, ()> {
+///     Ok(cell)
+/// }
+///
+/// let cell = Cell::new(State {
+///     count: 0,
+///     flag: false,
+/// });
+///
+/// munge!(let fields = find(&cell) => State { count, flag });
+/// let (count, flag) = fields.unwrap();
+/// count.set(1);
+/// flag.set(true);
+/// ```
 #[macro_export]
 macro_rules! munge {
     ($($t:tt)*) => { $crate::munge_with_path!($crate => $($t)*) }
 }
 
+/// Destructures a `&mut MaybeUninit` or `&mut ManuallyDrop` in a constant
+/// context.
+///
+/// `munge!` calls trait methods which can't be called in constant contexts.
+/// `munge_const!` accepts the same `let` destructures, but projects and
+/// restructures fields with `const fn`s instead. Patterns are checked the same
+/// way as with `munge!`.
+///
+/// Only `&mut MaybeUninit<T>` and `&mut ManuallyDrop<T>` may be destructured,
+/// and `ref` and `raw!` bindings are not supported. Mutable references and
+/// `MaybeUninit::write` can be used in constant contexts since Rust 1.83 and
+/// 1.85, which is why munge requires Rust 1.85.
+///
+/// # Example
+///
+/// ```
+/// # use core::mem::MaybeUninit;
+/// # use munge::munge_const;
+/// pub struct Entry {
+///     key: u32,
+///     value: (char, bool),
+/// }
+///
+/// const fn init(mu: &mut MaybeUninit<Entry>, key: u32) {
+///     munge_const!(let Entry { key: k, value: (c, b) } = mu);
+///     k.write(key);
+///     c.write('x');
+///     b.write(true);
+/// }
+///
+/// const ENTRY: Entry = {
+///     let mut mu = MaybeUninit::uninit();
+///     init(&mut mu, 42);
+///     // SAFETY: `mu` was completely initialized by `init`.
+///     unsafe { mu.assume_init() }
+/// };
+///
+/// assert_eq!(ENTRY.key, 42);
+/// assert_eq!(ENTRY.value, ('x', true));
+/// ```
+#[macro_export]
+macro_rules! munge_const {
+    ($($t:tt)*) => { $crate::munge_const_with_path!($crate => $($t)*) }
+}
+
+/// Initializes a `&mut MaybeUninit` in place from a struct literal.
+///
+/// `init!(mu => Struct { a: expr, b: (expr, expr) })` writes each value in the
+/// struct literal directly to its field of `mu`, then evaluates to a mutable
+/// reference to the initialized value. The struct is never built on the stack.
+///
+/// Struct, tuple, and array expressions in the literal are initialized field by
+/// field, and any other expression is a value which is written to its field.
+/// Tuple structs can be initialized with their field indices, like
+/// `Wrapper { 0: expr }`. Every field must be written, or else the remaining
+/// fields of a struct may be zero-filled with `..zeroed` if the struct
+/// implements [`Zeroable`]. Every value is evaluated before any field is
+/// written.
+///
+/// # Example
+///
+/// ```
+/// # use core::mem::MaybeUninit;
+/// # use munge::{init, Zeroable};
+/// pub struct Header {
+///     id: u32,
+///     flags: u8,
+///     checksum: u16,
+/// }
+///
+/// // SAFETY: `Header` only contains integers, which are valid when zeroed.
+/// unsafe impl Zeroable for Header {}
+///
+/// pub struct Big {
+///     header: Header,
+///     data: ([u8; 3], char),
+/// }
+///
+/// let mut mu = MaybeUninit::<Big>::uninit();
+/// let big = init!(&mut mu => Big {
+///     header: Header { id: 7, ..zeroed },
+///     data: ([1, 2, 3], 'x'),
+/// });
+///
+/// assert_eq!(big.header.id, 7);
+/// assert_eq!(big.header.checksum, 0);
+/// assert_eq!(big.data, ([1, 2, 3], 'x'));
+/// ```
+#[macro_export]
+macro_rules! init {
+    ($($t:tt)*) => { $crate::init_with_path!($crate => $($t)*) }
+}
+
+/// Finishes initializing the storage passed to [`slots`] from the tokens
+/// returned by writing its slots.
+///
+/// `finish!(f => Struct { a: token, b: (token, token) })` takes the [`Finish`]
+/// for some storage and a [`Written`] token for each field, in the same shape
+/// as the pattern that destructured the storage into slots. It evaluates to a
+/// mutable reference to the initialized value. Like with [`init!`], struct,
+/// tuple, and array expressions name their fields, and any other expression is
+/// a token. Every field must have a token from the same storage.
+///
+/// # Example
+///
+/// ```
+/// # use core::mem::MaybeUninit;
+/// # use munge::{finish, munge, slots};
+/// pub struct Pair {
+///     key: u32,
+///     value: [char; 2],
+/// }
+///
+/// let mut mu = MaybeUninit::<Pair>::uninit();
+/// let pair = slots(&mut mu, |uninit, f| {
+///     munge!(let Pair { key, value: [x, y] } = uninit);
+///     let (x, y) = (x.write('a'), y.write('b'));
+///     finish!(f => Pair { key: key.write(7), value: [x, y] })
+/// });
+///
+/// assert_eq!(pair.key, 7);
+/// assert_eq!(pair.value, ['a', 'b']);
+/// ```
+#[macro_export]
+macro_rules! finish {
+    ($($t:tt)*) => { $crate::finish_with_path!($crate => $($t)*) }
+}
+
+/// Destructures a type by matching it against refutable patterns.
+///
+/// `munge_match!(expr { arms })` destructures `expr` with the pattern of the
+/// first arm that matches it, then evaluates that arm with its bindings. Like
+/// `match`, the arms must be exhaustive. Match guards are not supported.
+///
+/// Only [`DiscriminantStable`] types may be destructured with refutable
+/// patterns. A single refutable pattern may also be destructured with `let`
+/// and `else`, like `munge!(let Some(x) = value else { ... })`.
+///
+/// # Example
+///
+/// ```
+/// # use core::mem::ManuallyDrop;
+/// # use munge::{munge, munge_match};
+/// pub enum Shape {
+///     Circle(f32),
+///     Rect { w: f32, h: f32 },
+/// }
+///
+/// let mut md = ManuallyDrop::new(Shape::Rect { w: 2.0, h: 3.0 });
+///
+/// let area = munge_match!(&mut md {
+///     Shape::Circle(r) => 3.14 * **r * **r,
+///     Shape::Rect { w, h } => {
+///         **h = 4.0;
+///         **w * **h
+///     }
+/// });
+/// assert_eq!(area, 8.0);
+///
+/// munge!(let Shape::Rect { h, .. } = &md else { unreachable!() });
+/// assert_eq!(**h, 4.0);
+/// ```
+#[macro_export]
+macro_rules! munge_match {
+    ($($t:tt)*) => { $crate::munge_with_path!($crate => match $($t)*) }
+}
+
 /// A type that can be destructured into its constituent parts.
 ///
 /// See the [crate docs](index.html#examples) for an example of implementing
@@ -113,6 +575,12 @@ pub unsafe trait Destructure: Sized {
 ///   value must behave as a disjoint borrow of a field of the underlying type.
 /// - If the type is destructured [by move](Move), then the `Restructured` value
 ///   must move the fields out of the underlying type.
+///
+/// When a type is destructured by move, each field is moved into
+/// [`restructure`](Restructure::restructure) when it is called. If
+/// `restructure` panics, it is responsible for dropping or leaking the field it
+/// was restructuring, and the fields which haven't been restructured yet are
+/// restructured and dropped while unwinding.
 pub unsafe trait Restructure<T: ?Sized>: Destructure {
     /// The restructured version of this type.
     type Restructured;
@@ -126,6 +594,96 @@ pub unsafe trait Restructure<T: ?Sized>: Destructure {
     unsafe fn restructure(&self, ptr: *mut T) -> Self::Restructured;
 }
 
+/// A type whose fields can be restructured as shared borrows.
+///
+/// Fields bound with `ref` are restructured with
+/// [`restructure_ref`](RestructureRef::restructure_ref) instead of
+/// [`restructure`](Restructure::restructure). This lets exclusive borrows like
+/// `&mut MaybeUninit<T>` hand out shared borrows of some of their fields, like
+/// `munge!(let (a, ref b) = &mut mu)`.
+///
+/// # Safety
+///
+/// [`restructure_ref`](RestructureRef::restructure_ref) must return a valid
+/// [`RestructuredRef`](RestructureRef::RestructuredRef) that behaves as a
+/// shared borrow of the same field that
+/// [`restructure`](Restructure::restructure) would borrow.
+#[diagnostic::on_unimplemented(
+    message = "munge may not restructure `ref` bindings from `{Self}`",
+    label = "`{Self}` can't restructure its fields as shared borrows",
+    note = "only borrows like `&mut MaybeUninit<T>` may use `ref` bindings"
+)]
+pub unsafe trait RestructureRef<T: ?Sized>: Restructure<T> {
+    /// The shared restructured version of this type.
+    type RestructuredRef;
+
+    /// Restructures a pointer to this type into a shared borrow of the target
+    /// type.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a properly aligned pointer to a subfield of the pointer
+    /// [`underlying`](Destructure::underlying) `self`.
+    unsafe fn restructure_ref(&self, ptr: *mut T) -> Self::RestructuredRef;
+}
+
+/// A type whose fields can be restructured as raw pointers.
+///
+/// Fields bound with `raw!(x)` are restructured with
+/// [`restructure_raw`](RestructureRaw::restructure_raw) instead of
+/// [`restructure`](Restructure::restructure). Raw pointers don't borrow the
+/// destructured value, so they may be used after the other restructured fields
+/// go out of scope.
+///
+/// # Safety
+///
+/// [`restructure_raw`](RestructureRaw::restructure_raw) must return a pointer
+/// to the same field that [`restructure`](Restructure::restructure) would
+/// restructure, with the same wrapper as the restructured field.
+#[diagnostic::on_unimplemented(
+    message = "munge may not restructure `raw!` bindings from `{Self}`",
+    label = "`{Self}` can't restructure its fields as raw pointers",
+    note = "only borrows like `&mut MaybeUninit<T>` may use `raw!` bindings"
+)]
+pub unsafe trait RestructureRaw<T: ?Sized>: Restructure<T> {
+    /// The raw pointer version of this type.
+    type RestructuredRaw;
+
+    /// Restructures a pointer to this type into a raw pointer to the target
+    /// type.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a properly aligned pointer to a subfield of the pointer
+    /// [`underlying`](Destructure::underlying) `self`.
+    unsafe fn restructure_raw(&self, ptr: *mut T) -> Self::RestructuredRaw;
+}
+
+/// A `repr(transparent)` wrapper type that munge can destructure through.
+///
+/// When the underlying type of a destructured value is `Transparent`, munge
+/// destructures its [`Inner`](Transparent::Inner) type instead and restructures
+/// each field wrapped in [`Wrapped`](Transparent::Wrapped). This lets munge see
+/// through stacks of wrappers like `&mut ManuallyDrop<MaybeUninit<T>>`.
+///
+/// Munge sees through up to three `Transparent` layers below the destructured
+/// value. In generic code, only layers that are known to be `Transparent` are
+/// seen through.
+///
+/// # Safety
+///
+/// - `Self` must have the same layout as [`Inner`](Transparent::Inner).
+/// - For every `U`, [`Wrapped<U>`](Transparent::Wrapped) must have the same
+///   layout as `U`, and must be the same wrapper around `U` as `Self` is around
+///   `Inner`. Any field of `Inner` must be valid to access as `Wrapped<U>`
+///   wherever `Self` is valid to access.
+pub unsafe trait Transparent {
+    /// The type wrapped by this type.
+    type Inner;
+    /// This wrapper around some other type.
+    type Wrapped<U>;
+}
+
 /// Destructuring by borrow, e.g. `let (a, b) = c` where `c` is a reference.
 ///
 /// Borrow destructuring leaves the original value intact, only borrowing from
@@ -143,9 +701,19 @@ impl<T: Destructure> internal::DestructuringFor<T> for Borrow {
 /// Destructuring by move, e.g. `let (a, b) = c` where `c` is a value.
 ///
 /// Move destructuring forgets the original value and moves each destructured
-/// field during restructuring. Move destructuring may not use rest patterns
-/// (`..`) because every field of the original value must be restructured, else
-/// they will be forgotten.
+/// field during restructuring. Move destructuring may only use rest patterns
+/// (`..`) on types which implement [`DropRest`], because every field of the
+/// original value must be restructured, else they will be forgotten. The fields
+/// matched by a rest pattern are restructured and dropped in place once the
+/// other fields of the value have been restructured.
+///
+/// If restructuring a field panics, the fields which haven't been restructured
+/// yet are restructured and dropped while unwinding instead of being leaked.
+///
+/// Fields matched by a wildcard (`_`) are restructured into hidden bindings
+/// which live until the end of the enclosing scope, just like the unmoved
+/// fields of a natively destructured value. They are dropped after the named
+/// bindings, in declaration order.
 pub struct Move;
 
 impl internal::Destructuring for Move {}
@@ -154,29 +722,215 @@ impl<T: Destructure> internal::DestructuringFor<T> for Move {
     type Destructurer = internal::Move<T>;
 }
 
-#[cfg(test)]
-mod tests {
-    use core::{cell::Cell, mem::MaybeUninit};
+/// A destructurable type which can read the discriminant of its underlying
+/// value.
+///
+/// Destructuring a `DiscriminantStable` type may use refutable patterns like
+/// `Some(x)` with `munge!(let ... else)` and [`munge_match!`].
+///
+/// Pinned references to `ManuallyDrop` are discriminant-stable, but only
+/// restructure fields which are `Unpin`. The destructured type may not
+/// structurally pin its fields, so projecting a pin to them would be unsound.
+///
+/// # Safety
+///
+/// - The underlying value must always be initialized, and may not be modified
+///   by anything other than the destructured value while it is borrowed.
+/// - [`Access`](DiscriminantStable::Access) must reflect how the underlying
+///   value may be accessed:
+///   - [`Shared`] if it may be accessed through a shared reference.
+///   - [`Exclusive`] if it may be accessed through a mutable reference.
+#[diagnostic::on_unimplemented(
+    message = "munge may not destructure `{Self}` with refutable patterns",
+    label = "the discriminant of this value may not be read",
+    note = "only `DiscriminantStable` types may be matched against"
+)]
+pub unsafe trait DiscriminantStable: Destructure {
+    /// The type of access to the underlying value.
+    type Access: internal::Access;
+}
 
-    #[test]
-    fn project_tuple() {
-        let mut mu = MaybeUninit::<(u32, char)>::uninit();
+/// A destructurable type whose restructured fields may alias each other.
+///
+/// Types which implement `Aliasable` may bind a field and its subfields at the
+/// same time with `@` subpatterns, like
+/// `munge!(let Outer { inner: whole @ Inner { a, .. }, .. } = &cell)`.
+///
+/// # Safety
+///
+/// Values restructured from a field and from any of its subfields must be able
+/// to exist at the same time. This holds for shared borrows like `&Cell<T>`,
+/// but not for exclusive borrows like `&mut MaybeUninit<T>` or for types which
+/// are destructured by move.
+#[diagnostic::on_unimplemented(
+    message = "munge may not bind a field and its subfields through `{Self}`",
+    label = "`@` subpatterns require an `Aliasable` destructuring",
+    note = "only shared borrows like `&Cell<T>` may alias their fields"
+)]
+pub unsafe trait Aliasable: Destructure {}
 
-        munge!(let (a, b) = &mut mu);
-        assert_eq!(a.write(1), &1);
-        assert_eq!(b.write('a'), &'a');
-        munge!(let (a, b,) = &mut mu);
-        assert_eq!(a.write(2), &2);
-        assert_eq!(b.write('b'), &'b');
+/// A destructurable type which may destructure the fields of unions.
+///
+/// Union fields are marked with `#[union]` in a struct pattern, like
+/// `munge!(let MyUnion { #[union] field } = &mut mu)`. Only types which
+/// implement `ProjectUnions` may destructure union fields, and unmarked union
+/// fields are always rejected.
+///
+/// # Safety
+///
+/// Restructuring any field of a union must be sound, regardless of which field
+/// of the union was last written. This holds for wrappers like `MaybeUninit`
+/// which never assume that their contents are initialized.
+#[diagnostic::on_unimplemented(
+    message = "munge may not destructure union fields through `{Self}`",
+    label = "`{Self}` can't soundly access union fields",
+    note = "only `ProjectUnions` types may destructure union fields"
+)]
+pub unsafe trait ProjectUnions: Destructure {}
 
-        munge!(let (a, _) = &mut mu);
-        assert_eq!(a.write(3), &3);
-        munge!(let (_, b) = &mut mu);
-        assert_eq!(b.write('c'), &'c');
-        munge!(let (a, _,) = &mut mu);
-        assert_eq!(a.write(3), &3);
-        munge!(let (_, b,) = &mut mu);
-        assert_eq!(b.write('c'), &'c');
+/// An enum with a primitive representation whose variants may be emplaced.
+///
+/// This trait should be implemented by deriving [`Emplace`](macro@Emplace).
+///
+/// # Safety
+///
+/// The enum must have a primitive representation like `#[repr(u8)]` or
+/// `#[repr(C, u8)]`. For each variant, the type must have a hidden inherent
+/// function `__munge_emplace_{Variant}` which writes the discriminant of the
+/// variant through a pointer to the enum and returns pointers to each field of
+/// the variant's payload.
+#[diagnostic::on_unimplemented(
+    message = "munge may not emplace variants of `{Self}`",
+    label = "`{Self}` does not implement `Emplace`",
+    note = "derive `Emplace` on enums with a primitive representation"
+)]
+pub unsafe trait Emplace {}
+
+/// A struct whose fields are all known to munge, so that it may be destructured
+/// by move with a rest pattern (`..`).
+///
+/// The fields matched by the rest pattern are restructured with `R` and
+/// dropped in place once the other fields have been restructured, like
+/// `munge!(let Config { name, .. } = Cell::new(config))`. If dropping one of
+/// them panics, the fields after it are leaked.
+///
+/// This trait should be implemented by deriving [`DropRest`](macro@DropRest).
+///
+/// # Example
+///
+/// ```
+/// # use core::cell::Cell;
+/// # use munge::{munge, DropRest};
+/// #[derive(DropRest)]
+/// pub struct Config {
+///     name: String,
+///     tags: Vec<String>,
+/// }
+///
+/// let config = Config {
+///     name: "example".to_string(),
+///     tags: vec!["a".to_string()],
+/// };
+/// munge!(let Config { name, .. } = Cell::new(config));
+/// assert_eq!(name.into_inner(), "example");
+/// ```
+///
+/// # Safety
+///
+/// `drop_rest` must restructure each field of the value that `ptr` points to
+/// which is not in `named` exactly once with `restructurer`, and drop it. It
+/// may not access the fields in `named`.
+#[diagnostic::on_unimplemented(
+    message = "munge may not destructure a rest pattern of `{Self}` by move",
+    label = "`{Self}` does not implement `DropRest`",
+    note = "derive `DropRest` on structs to drop the fields of rest patterns"
+)]
+pub unsafe trait DropRest<R> {
+    /// Restructures and drops each field of the value that `ptr` points to
+    /// which is not in `named`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a properly-aligned pointer to a subfield of the pointer
+    /// underlying `restructurer`, and the fields which are not in `named` must
+    /// not have been restructured.
+    #[doc(hidden)]
+    unsafe fn drop_rest(
+        ptr: *mut Self,
+        restructurer: &R,
+        named: __macro::Named<'_>,
+    );
+}
+
+/// A type which is valid when all of its bytes are zero.
+///
+/// Fields which are left out of an [`init!`] with `..zeroed` are zero-filled,
+/// which requires the struct being initialized to be `Zeroable`.
+///
+/// # Safety
+///
+/// A value of the type with every byte set to zero must be a valid instance of
+/// the type.
+#[diagnostic::on_unimplemented(
+    message = "munge may not zero-fill `{Self}`",
+    label = "`{Self}` is not valid when zeroed",
+    note = "only `Zeroable` types may be initialized with `..zeroed`"
+)]
+pub unsafe trait Zeroable {}
+
+/// Shared access to the underlying value, e.g. through `&ManuallyDrop<T>`.
+pub struct Shared;
+
+impl internal::Access for Shared {
+    type Ref<'a, T: 'a + ?Sized> = &'a T;
+
+    unsafe fn access<'a, T: ?Sized>(ptr: *mut T) -> Self::Ref<'a, T> {
+        // SAFETY: The caller has guaranteed that `ptr` is non-null, properly
+        // aligned, and valid for reads.
+        unsafe { &*ptr }
+    }
+}
+
+/// Exclusive access to the underlying value, e.g. through
+/// `&mut ManuallyDrop<T>`.
+pub struct Exclusive;
+
+impl internal::Access for Exclusive {
+    type Ref<'a, T: 'a + ?Sized> = &'a mut T;
+
+    unsafe fn access<'a, T: ?Sized>(ptr: *mut T) -> Self::Ref<'a, T> {
+        // SAFETY: The caller has guaranteed that `ptr` is non-null, properly
+        // aligned, and valid for reads and writes.
+        unsafe { &mut *ptr }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{
+        cell::Cell,
+        mem::{ManuallyDrop, MaybeUninit},
+    };
+
+    #[test]
+    fn project_tuple() {
+        let mut mu = MaybeUninit::<(u32, char)>::uninit();
+
+        munge!(let (a, b) = &mut mu);
+        assert_eq!(a.write(1), &1);
+        assert_eq!(b.write('a'), &'a');
+        munge!(let (a, b,) = &mut mu);
+        assert_eq!(a.write(2), &2);
+        assert_eq!(b.write('b'), &'b');
+
+        munge!(let (a, _) = &mut mu);
+        assert_eq!(a.write(3), &3);
+        munge!(let (_, b) = &mut mu);
+        assert_eq!(b.write('c'), &'c');
+        munge!(let (a, _,) = &mut mu);
+        assert_eq!(a.write(3), &3);
+        munge!(let (_, b,) = &mut mu);
+        assert_eq!(b.write('c'), &'c');
 
         munge!(let (mut a, mut b) = &mut mu);
         assert_eq!(a.write(4), &4);
@@ -301,143 +1055,1160 @@ mod tests {
     }
 
     #[test]
-    fn project_tuple_struct() {
-        struct Example(u32, char);
+    fn project_tuple_struct() {
+        struct Example(u32, char);
+
+        let mut mu = MaybeUninit::<Example>::uninit();
+
+        munge!(let Example(a, b) = &mut mu);
+        assert_eq!(a.write(1), &1);
+        assert_eq!(b.write('a'), &'a');
+        munge!(let Example(a, b,) = &mut mu);
+        assert_eq!(a.write(2), &2);
+        assert_eq!(b.write('b'), &'b');
+
+        munge!(let Example(a, _) = &mut mu);
+        assert_eq!(a.write(3), &3);
+        munge!(let Example(_, b) = &mut mu);
+        assert_eq!(b.write('c'), &'c');
+        munge!(let Example(a, _,) = &mut mu);
+        assert_eq!(a.write(3), &3);
+        munge!(let Example(_, b,) = &mut mu);
+        assert_eq!(b.write('d'), &'d');
+
+        munge!(let Example(mut a, mut b) = &mut mu);
+        assert_eq!(a.write(4), &4);
+        assert_eq!(b.write('e'), &'e');
+        let mut new_a = MaybeUninit::uninit();
+        a = &mut new_a;
+        let mut new_b = MaybeUninit::uninit();
+        b = &mut new_b;
+        let _ = a;
+        let _ = b;
+
+        munge!(let Example(a, ..) = &mut mu);
+        assert_eq!(a.write(5), &5);
+
+        // SAFETY: `mu` is completely initialized.
+        let init = unsafe { mu.assume_init() };
+        assert_eq!(init.0, 5);
+        assert_eq!(init.1, 'e');
+    }
+
+    #[test]
+    fn project_generic() {
+        struct Example<T>(u32, T);
+
+        let mut mu = MaybeUninit::<Example<char>>::uninit();
+
+        munge!(let Example(a, b) = &mut mu);
+        assert_eq!(a.write(1), &1);
+        assert_eq!(b.write('a'), &'a');
+        munge!(let Example(a, b,) = &mut mu);
+        assert_eq!(a.write(2), &2);
+        assert_eq!(b.write('b'), &'b');
+
+        munge!(let Example(a, _) = &mut mu);
+        assert_eq!(a.write(3), &3);
+        munge!(let Example(_, b) = &mut mu);
+        assert_eq!(b.write('c'), &'c');
+        munge!(let Example(a, _,) = &mut mu);
+        assert_eq!(a.write(3), &3);
+        munge!(let Example(_, b,) = &mut mu);
+        assert_eq!(b.write('c'), &'c');
+
+        munge!(let Example(a, ..) = &mut mu);
+        assert_eq!(a.write(4), &4);
+
+        // SAFETY: `mu` is completely initialized.
+        let init = unsafe { mu.assume_init() };
+        assert_eq!(init.0, 4);
+        assert_eq!(init.1, 'c');
+
+        let mut mu = MaybeUninit::<Example<Example<char>>>::uninit();
+
+        munge!(
+            let Example::<Example<char>>(a, Example::<char>(b, c)) = &mut mu;
+        );
+        assert_eq!(a.write(1), &1);
+        assert_eq!(b.write(2), &2);
+        assert_eq!(c.write('a'), &'a');
+
+        // SAFETY: `mu` is completely initialized.
+        let init = unsafe { mu.assume_init() };
+        assert_eq!(init.0, 1);
+        assert_eq!(init.1 .0, 2);
+        assert_eq!(init.1 .1, 'a');
+    }
+
+    #[test]
+    fn project_nested_struct() {
+        struct Inner {
+            a: u32,
+            b: char,
+        }
+        struct Outer {
+            inner: Inner,
+            c: i32,
+        }
+
+        let mut mu = MaybeUninit::<Outer>::uninit();
+
+        munge!(let Outer { inner: Inner { a, b }, c } = &mut mu);
+        assert_eq!(a.write(1), &1);
+        assert_eq!(b.write('a'), &'a');
+        assert_eq!(c.write(2), &2);
+
+        // SAFETY: `mu` is completely initialized.
+        let init = unsafe { mu.assume_init() };
+        assert_eq!(init.inner.a, 1);
+        assert_eq!(init.inner.b, 'a');
+        assert_eq!(init.c, 2);
+    }
+
+    #[test]
+    fn project_nested_tuple() {
+        let mut mu = MaybeUninit::<(u32, (char, u32))>::uninit();
+
+        munge!(let (a, (b, c)) = &mut mu);
+        assert_eq!(a.write(1), &1);
+        assert_eq!(b.write('a'), &'a');
+        assert_eq!(c.write(2), &2);
+
+        // SAFETY: `mu` is completely initialized.
+        let init = unsafe { mu.assume_init() };
+        assert_eq!(init, (1, ('a', 2)));
+    }
+
+    #[test]
+    fn project_nested_array() {
+        let mut mu = MaybeUninit::<[[u32; 2]; 2]>::uninit();
+
+        munge!(let [a, [b, c]] = &mut mu);
+        assert_eq!(a.write([1, 2]), &[1, 2]);
+        assert_eq!(b.write(3), &3);
+        assert_eq!(c.write(4), &4);
+
+        // SAFETY: `mu` is completely initialized.
+        let init = unsafe { mu.assume_init() };
+        assert_eq!(init, [[1, 2], [3, 4]]);
+    }
+
+    #[test]
+    fn project_array_rest() {
+        let mut mu = MaybeUninit::<[u32; 4]>::uninit();
+
+        munge!(let [a, b, ..] = &mut mu);
+        assert_eq!(a.write(1), &1);
+        assert_eq!(b.write(2), &2);
+        munge!(let [.., c, d] = &mut mu);
+        assert_eq!(c.write(3), &3);
+        assert_eq!(d.write(4), &4);
+
+        // SAFETY: `mu` is completely initialized.
+        let init = unsafe { mu.assume_init() };
+        assert_eq!(init, [1, 2, 3, 4]);
+
+        let mut mu = MaybeUninit::<[u32; 4]>::uninit();
+
+        munge!(let [a, .., d] = &mut mu);
+        assert_eq!(a.write(5), &5);
+        assert_eq!(d.write(8), &8);
+        munge!(let [_, b, c, _] = &mut mu);
+        assert_eq!(b.write(6), &6);
+        assert_eq!(c.write(7), &7);
+
+        // SAFETY: `mu` is completely initialized.
+        let init = unsafe { mu.assume_init() };
+        assert_eq!(init, [5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn project_const_generic_array() {
+        fn init<const N: usize>(mu: &mut MaybeUninit<[u32; N]>) {
+            munge!(let [first, .., last] = mu);
+            first.write(1);
+            last.write(2);
+        }
+
+        fn get<const N: usize>(cell: &Cell<[(u32, char); N]>) -> (u32, char) {
+            munge!(let [(a, _), .., (_, b)] = cell);
+            (a.get(), b.get())
+        }
+
+        let mut mu = MaybeUninit::<[u32; 2]>::uninit();
+        init(&mut mu);
+        // SAFETY: `mu` is completely initialized.
+        let init_2 = unsafe { mu.assume_init() };
+        assert_eq!(init_2, [1, 2]);
+
+        let mut mu = MaybeUninit::<[u32; 5]>::uninit();
+        init(&mut mu);
+        // SAFETY: The first and last elements of `mu` are initialized.
+        let init_5 = unsafe { mu.assume_init_ref() };
+        assert_eq!(init_5[0], 1);
+        assert_eq!(init_5[4], 2);
+
+        let cell = Cell::new([(1, 'a'), (2, 'b'), (3, 'c')]);
+        assert_eq!(get(&cell), (1, 'c'));
+        let cell = Cell::new([(4, 'd'), (5, 'e')]);
+        assert_eq!(get(&cell), (4, 'e'));
+    }
+
+    #[test]
+    fn project_columns() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Particle {
+            pos: (f32, f32),
+            vel: (f32, f32),
+            id: u32,
+        }
+
+        let mut particles = [MaybeUninit::<Particle>::uninit(); 3];
+
+        munge!(let [Particle { pos, vel: (vx, vy), .. }; _] = &mut particles);
+        let _: [&mut MaybeUninit<(f32, f32)>; 3] = pos;
+        for (i, pos) in pos.into_iter().enumerate() {
+            pos.write((i as f32, 0.0));
+        }
+        for vx in vx {
+            vx.write(1.0);
+        }
+        for vy in vy {
+            vy.write(2.0);
+        }
+        munge!(let [Particle { id, .. }; 3] = &mut particles);
+        for (i, id) in id.into_iter().enumerate() {
+            id.write(i as u32);
+        }
+
+        for (i, particle) in particles.iter().enumerate() {
+            // SAFETY: Every field of every particle was initialized.
+            let particle = unsafe { particle.assume_init() };
+            assert_eq!(
+                particle,
+                Particle {
+                    pos: (i as f32, 0.0),
+                    vel: (1.0, 2.0),
+                    id: i as u32,
+                },
+            );
+        }
+
+        let cells = [Cell::new((1, 'a')), Cell::new((2, 'b'))];
+        munge!(let [(mut a, b); _] = &cells);
+        assert_eq!(a.map(Cell::get), [1, 2]);
+        assert_eq!(b.map(Cell::get), ['a', 'b']);
+        a.reverse();
+        assert_eq!(a.map(Cell::get), [2, 1]);
+
+        let values = [Cell::new((3, 'c')), Cell::new((4, 'd'))];
+        munge!(let [(a, _); _] = values);
+        assert_eq!(a.map(Cell::into_inner), [3, 4]);
+
+        let cells = [Cell::new((5, 'e')), Cell::new((6, 'f'))];
+        let slice: &[Cell<(i32, char)>] = &cells;
+        munge!(let [(a, b); 2] = slice);
+        a[1].set(7);
+        assert_eq!(b.map(Cell::get), ['e', 'f']);
+        assert_eq!(cells[1].get(), (7, 'f'));
+
+        let mut particles = [MaybeUninit::<Particle>::uninit(); 2];
+        let slice: &mut [MaybeUninit<Particle>] = &mut particles;
+        munge!(let [Particle { id, .. }; 2] = slice);
+        let _: [&mut MaybeUninit<u32>; 2] = id;
+    }
+
+    #[test]
+    #[should_panic = "munge may only destructure a slice of length 3 into \
+                      columns, but the slice has length 2"]
+    fn project_columns_of_short_slice() {
+        let cells = [Cell::new((1, 'a')), Cell::new((2, 'b'))];
+        let slice: &[Cell<(i32, char)>] = &cells;
+        munge!(let [(_a, _b); 3] = slice);
+    }
+
+    #[test]
+    fn lift_option() {
+        struct Header {
+            len: u16,
+            kind: u8,
+        }
+
+        let mut mu = MaybeUninit::<Header>::uninit();
+
+        munge!(let fields = Some(&mut mu) => Header { len, kind });
+        let (len, kind) = fields.unwrap();
+        assert_eq!(len.write(10), &10);
+        assert_eq!(kind.write(2), &2);
+
+        let none = None::<&mut MaybeUninit<Header>>;
+        munge!(let fields = none => Header { len, .. });
+        assert!(fields.is_none());
+
+        // SAFETY: `mu` is completely initialized.
+        let init = unsafe { mu.assume_init() };
+        assert_eq!(init.len, 10);
+        assert_eq!(init.kind, 2);
+
+        let value = Some(Cell::new((1, ('a', 2))));
+        munge!(let fields = value => (a, (_, mut b)));
+        let (a, b) = fields.unwrap();
+        assert_eq!(a.into_inner(), 1);
+        assert_eq!(b.into_inner(), 2);
+    }
+
+    #[test]
+    fn lift_result() {
+        let cell = Cell::new((1, 'a'));
+
+        munge!(let fields = Ok::<_, ()>(&cell) => (a, b));
+        let (a, b) = fields.unwrap();
+        assert_eq!(a.get(), 1);
+        a.set(2);
+        assert_eq!(b.get(), 'a');
+        assert_eq!(cell.get(), (2, 'a'));
+
+        munge!(let fields = Err::<&Cell<(u32, char)>, _>("error") => (a, _));
+        assert_eq!(fields.map(|(a,)| a.get()), Err("error"));
+    }
+
+    #[test]
+    fn project_unsized_tail() {
+        use core::mem::ManuallyDrop;
+
+        struct Packet<D: ?Sized> {
+            len: u16,
+            kind: u8,
+            data: D,
+        }
+
+        let sized = Cell::new(Packet {
+            len: 4,
+            kind: 1,
+            data: [1, 2, 3, 4],
+        });
+        let cell: &Cell<Packet<[u8]>> = &sized;
+
+        munge!(let Packet { len, kind, data } = cell);
+        assert_eq!(len.get(), 4);
+        kind.set(2);
+        let data: &Cell<[u8]> = data;
+        assert_eq!(data.as_slice_of_cells().len(), 4);
+        data.as_slice_of_cells()[3].set(5);
+
+        munge!(let Packet { data: [first, .., last], .. } = cell);
+        assert_eq!(first.get(), 1);
+        last.set(6);
+
+        let packet = sized.into_inner();
+        assert_eq!(packet.kind, 2);
+        assert_eq!(packet.data, [1, 2, 3, 6]);
+
+        let mut sized = ManuallyDrop::new(Packet {
+            len: 2,
+            kind: 0,
+            data: [10u32, 20],
+        });
+        let md: &mut ManuallyDrop<Packet<[u32]>> = &mut sized;
+
+        munge!(let Packet { data, .. } = md);
+        let data: &mut ManuallyDrop<[u32]> = data;
+        assert_eq!(**data, [10, 20]);
+        data[1] = 30;
+        assert_eq!(sized.data, [10, 30]);
+    }
+
+    #[test]
+    fn project_tuple_of_values() {
+        struct Header {
+            len: u16,
+            kind: u8,
+        }
+
+        let mut mu1 = MaybeUninit::<(u32, char)>::uninit();
+        let mut mu2 = MaybeUninit::<Header>::uninit();
+
+        munge!(let ((a, b), Header { len, .. }) = (&mut mu1, &mut mu2));
+        assert_eq!(a.write(1), &1);
+        assert_eq!(b.write('a'), &'a');
+        assert_eq!(len.write(2), &2);
+        munge!(let (_, Header { kind, .. }) = (&mu1, &mut mu2));
+        assert_eq!(kind.write(3), &3);
+
+        // SAFETY: `mu1` and `mu2` are completely initialized.
+        let (init1, init2) = unsafe { (mu1.assume_init(), mu2.assume_init()) };
+        assert_eq!(init1, (1, 'a'));
+        assert_eq!(init2.len, 2);
+        assert_eq!(init2.kind, 3);
+
+        let cell = Cell::new((1, 2));
+        let md = core::mem::ManuallyDrop::new([3, 4]);
+        munge!(let ((mut a, b), ([c, d], e)) = (&cell, (md, 5)));
+        assert_eq!(a.get(), 1);
+        assert_eq!(b.get(), 2);
+        assert_eq!(*c, 3);
+        assert_eq!(*d, 4);
+        assert_eq!(e, 5);
+        a = b;
+        let _ = a;
+    }
+
+    #[test]
+    fn project_nested_wrappers() {
+        use core::{cell::UnsafeCell, mem::ManuallyDrop};
+
+        struct Example {
+            a: u32,
+            b: (char, f32),
+        }
+
+        let mut md = ManuallyDrop::new(MaybeUninit::<Example>::uninit());
+
+        munge!(let Example { a, b: (c, f) } = &mut md);
+        let a: &mut ManuallyDrop<MaybeUninit<u32>> = a;
+        assert_eq!(a.write(1), &1);
+        assert_eq!(c.write('a'), &'a');
+        assert_eq!(f.write(1.5), &1.5);
+
+        // SAFETY: `md` is completely initialized.
+        let init = unsafe { ManuallyDrop::into_inner(md).assume_init() };
+        assert_eq!(init.a, 1);
+        assert_eq!(init.b, ('a', 1.5));
+
+        let cell = Cell::new(ManuallyDrop::new((1, 'a')));
+        munge!(let (a, b) = &cell);
+        let a: &Cell<ManuallyDrop<u32>> = a;
+        a.set(ManuallyDrop::new(2));
+        assert_eq!(*b.get(), 'a');
+        assert_eq!(*cell.get(), (2, 'a'));
+
+        let mut cell = Cell::new(ManuallyDrop::new(UnsafeCell::new((1, 'a'))));
+        munge!(let (a, b) = &mut cell);
+        let a: &mut Cell<ManuallyDrop<UnsafeCell<u32>>> = a;
+        *a.get_mut().get_mut() = 2;
+        assert_eq!(*b.get_mut().get_mut(), 'a');
+        assert_eq!(*cell.get_mut().get_mut(), (2, 'a'));
+
+        let value = ManuallyDrop::new(Cell::new((3, 'b')));
+        munge!(let (a, b) = value);
+        let a: ManuallyDrop<Cell<u32>> = a;
+        assert_eq!(a.get(), 3);
+        assert_eq!(b.get(), 'b');
+
+        fn generic<T, U>(
+            value: &mut ManuallyDrop<MaybeUninit<(T, U)>>,
+            t: T,
+            u: U,
+        ) {
+            munge!(let (a, b) = value);
+            a.write(t);
+            b.write(u);
+        }
+
+        let mut md = ManuallyDrop::new(MaybeUninit::uninit());
+        generic(&mut md, 4, 'c');
+        // SAFETY: `md` is completely initialized.
+        let init = unsafe { ManuallyDrop::into_inner(md).assume_init() };
+        assert_eq!(init, (4, 'c'));
+
+        fn opaque<T>(value: &mut ManuallyDrop<MaybeUninit<T>>) {
+            munge!(let inner = value);
+            let _: &mut ManuallyDrop<MaybeUninit<T>> = inner;
+        }
+
+        let mut md = ManuallyDrop::new(MaybeUninit::<Cell<u32>>::uninit());
+        opaque(&mut md);
+    }
+
+    #[test]
+    fn expression() {
+        struct Example {
+            a: u32,
+            b: (char, f32),
+        }
+
+        fn fields(
+            mu: &mut MaybeUninit<Example>,
+        ) -> (&mut MaybeUninit<u32>, &mut MaybeUninit<char>) {
+            munge!(mu => Example { a, b: (c, _) })
+        }
+
+        let mut mu = MaybeUninit::<Example>::uninit();
+        let (a, c) = fields(&mut mu);
+        assert_eq!(a.write(1), &1);
+        assert_eq!(c.write('a'), &'a');
+        let (f,) = munge!(&mut mu => Example { b: (_, mut f), .. });
+        assert_eq!(f.write(1.5), &1.5);
+
+        // SAFETY: `mu` is completely initialized.
+        let init = unsafe { mu.assume_init() };
+        assert_eq!(init.a, 1);
+        assert_eq!(init.b, ('a', 1.5));
+
+        let cell = Cell::new((1, 'a'));
+        let value = match Some(&cell) {
+            Some(cell) => munge!(cell => (a, _)).0.get(),
+            None => 0,
+        };
+        assert_eq!(value, 1);
+
+        let fields = munge!(Some(&cell) => (_, b));
+        assert_eq!(fields.map(|(b,)| b.get()), Some('a'));
+        let () = munge!(&cell => (_, _));
+    }
+
+    #[test]
+    fn params() {
+        struct Config {
+            a: u32,
+            b: (char, f32),
+        }
+
+        #[crate::params(crate = crate)]
+        fn init(
+            #[munge] Config { a, b: (c, mut f) }: &mut MaybeUninit<Config>,
+            value: u32,
+            #[munge] (x, _): &Cell<(u32, u32)>,
+            (y, z): (u32, u32),
+        ) {
+            assert_eq!((y, z), (2, 3));
+            a.write(value);
+            c.write('a');
+            f.write(1.5);
+            let mut new_f = MaybeUninit::uninit();
+            f = &mut new_f;
+            let _ = f;
+            x.set(value);
+        }
+
+        let mut mu = MaybeUninit::<Config>::uninit();
+        let cell = Cell::new((0, 0));
+        init(&mut mu, 1, &cell, (2, 3));
+        assert_eq!(cell.get(), (1, 0));
+
+        // SAFETY: `mu` is completely initialized.
+        let init = unsafe { mu.assume_init() };
+        assert_eq!(init.a, 1);
+        assert_eq!(init.b, ('a', 1.5));
+
+        #[crate::params(crate = crate)]
+        fn sum(cells: &[Cell<(u32, u32)>]) -> u32 {
+            cells
+                .iter()
+                .map(|#[munge] (a, b): &Cell<(u32, u32)>| a.get() + b.get())
+                .sum()
+        }
+
+        let cells = [Cell::new((1, 2)), Cell::new((3, 4))];
+        assert_eq!(sum(&cells), 10);
+    }
+
+    #[test]
+    fn let_else() {
+        let mut md = ManuallyDrop::new(Some((1u32, 'a')));
+
+        munge!(let Some((a, mut b)) = &mut md else { panic!() });
+        **a = 2;
+        **b = 'b';
+        let mut new_b = ManuallyDrop::new('c');
+        b = &mut new_b;
+        let _ = b;
+        assert_eq!(*md, Some((2, 'b')));
+
+        let none = ManuallyDrop::new(None::<u32>);
+        munge!(let Some(_) = &none else {
+            return;
+        });
+        unreachable!();
+    }
+
+    #[test]
+    fn emplace() {
+        #[derive(crate::Emplace, Debug, PartialEq)]
+        #[munge(crate = crate)]
+        #[repr(u8)]
+        enum Prim<T> {
+            A(u8, u32),
+            B { a: T, b: u16 } = 10,
+            C,
+        }
+
+        let mut mu = MaybeUninit::<Prim<char>>::uninit();
+        munge!(let Prim::A(a, b) = emplace &mut mu);
+        a.write(1);
+        b.write(2);
+        // SAFETY: `mu` is completely initialized.
+        assert_eq!(unsafe { mu.assume_init_read() }, Prim::A(1, 2));
+
+        munge!(let Prim::B { b, a } = emplace &mut mu);
+        a.write('a');
+        b.write(3);
+        // SAFETY: `mu` is completely initialized.
+        assert_eq!(unsafe { mu.assume_init_read() }, Prim::B { a: 'a', b: 3 });
+
+        munge!(let Prim::C = emplace &mut mu);
+        // SAFETY: `mu` is completely initialized.
+        assert_eq!(unsafe { mu.assume_init_read() }, Prim::C);
+
+        #[derive(crate::Emplace, Debug, PartialEq)]
+        #[munge(crate = crate)]
+        #[repr(C, u16)]
+        enum C {
+            A(u8, (u32, char)),
+            B { a: u64 },
+        }
+
+        let mut mu = MaybeUninit::<C>::uninit();
+        munge!(let C::A(a, (b, c)) = emplace &mut mu);
+        a.write(1);
+        b.write(2);
+        c.write('c');
+        // SAFETY: `mu` is completely initialized.
+        assert_eq!(unsafe { mu.assume_init_read() }, C::A(1, (2, 'c')));
+
+        munge!(let C::A(a, ..) = emplace &mut mu);
+        a.write(4);
+        // SAFETY: The rest of the payload of `C::A` was initialized above.
+        assert_eq!(unsafe { mu.assume_init_read() }, C::A(4, (2, 'c')));
+
+        munge!(let C::B { a } = emplace &mut mu);
+        a.write(3);
+        // SAFETY: `mu` is completely initialized.
+        assert_eq!(unsafe { mu.assume_init_read() }, C::B { a: 3 });
+    }
+
+    #[test]
+    fn project_union() {
+        #[repr(C)]
+        union Example {
+            a: u32,
+            b: (u8, u8),
+        }
 
         let mut mu = MaybeUninit::<Example>::uninit();
+        munge!(let Example { #[union] b: (x, y) } = &mut mu);
+        x.write(1);
+        y.write(2);
+        // SAFETY: `b` is completely initialized.
+        assert_eq!(unsafe { mu.assume_init_ref().b }, (1, 2));
+
+        munge!(let Example { #[union] a } = &mut mu);
+        a.write(3);
+        // SAFETY: `a` is completely initialized.
+        assert_eq!(unsafe { mu.assume_init_ref().a }, 3);
+
+        munge!(let Example { #[union] a } = &mu);
+        // SAFETY: `a` is completely initialized.
+        assert_eq!(unsafe { a.assume_init_read() }, 3);
+
+        munge!(let Example { #[union] a } = mu);
+        // SAFETY: `a` is completely initialized.
+        assert_eq!(unsafe { a.assume_init() }, 3);
+    }
 
-        munge!(let Example(a, b) = &mut mu);
-        assert_eq!(a.write(1), &1);
-        assert_eq!(b.write('a'), &'a');
-        munge!(let Example(a, b,) = &mut mu);
-        assert_eq!(a.write(2), &2);
-        assert_eq!(b.write('b'), &'b');
+    #[test]
+    fn subpattern_bindings() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Inner {
+            a: u32,
+            b: char,
+        }
 
-        munge!(let Example(a, _) = &mut mu);
-        assert_eq!(a.write(3), &3);
-        munge!(let Example(_, b) = &mut mu);
-        assert_eq!(b.write('c'), &'c');
-        munge!(let Example(a, _,) = &mut mu);
-        assert_eq!(a.write(3), &3);
-        munge!(let Example(_, b,) = &mut mu);
-        assert_eq!(b.write('d'), &'d');
+        struct Outer {
+            inner: Inner,
+            c: u8,
+        }
 
-        munge!(let Example(mut a, mut b) = &mut mu);
-        assert_eq!(a.write(4), &4);
-        assert_eq!(b.write('e'), &'e');
-        let mut new_a = MaybeUninit::uninit();
-        a = &mut new_a;
-        let mut new_b = MaybeUninit::uninit();
-        b = &mut new_b;
-        let _ = a;
-        let _ = b;
+        let cell = Cell::new(Outer {
+            inner: Inner { a: 1, b: 'a' },
+            c: 2,
+        });
+        munge!(let Outer { inner: whole @ Inner { a, .. }, c } = &cell);
+        a.set(3);
+        assert_eq!(whole.get(), Inner { a: 3, b: 'a' });
+        whole.set(Inner { a: 4, b: 'b' });
+        assert_eq!(a.get(), 4);
+        assert_eq!(c.get(), 2);
 
-        munge!(let Example(a, ..) = &mut mu);
-        assert_eq!(a.write(5), &5);
+        let md = ManuallyDrop::new((1u32, (2u32, 3u32)));
+        munge!(let (_, pair @ (x, _)) = &md);
+        assert_eq!(**pair, (2, 3));
+        assert_eq!(**x, 2);
 
-        // SAFETY: `mu` is completely initialized.
-        let init = unsafe { mu.assume_init() };
-        assert_eq!(init.0, 5);
-        assert_eq!(init.1, 'e');
+        let value = Some((1u32, 2u32));
+        let md = ManuallyDrop::new(value);
+        munge!(let Some(pair @ (x, _)) = &md else { unreachable!() });
+        assert_eq!(**pair, (1, 2));
+        assert_eq!(**x, 1);
     }
 
     #[test]
-    fn project_generic() {
-        struct Example<T>(u32, T);
+    #[allow(unused_parens)]
+    fn paren_type_and_path_patterns() {
+        struct Unit;
+
+        impl Unit {
+            fn first(mu: &mut MaybeUninit<(u8, Self)>) -> &mut MaybeUninit<u8> {
+                munge!(let (a, Self) = mu);
+                a
+            }
+        }
 
-        let mut mu = MaybeUninit::<Example<char>>::uninit();
+        #[allow(dead_code)]
+        enum Single {
+            Only,
+        }
 
-        munge!(let Example(a, b) = &mut mu);
-        assert_eq!(a.write(1), &1);
-        assert_eq!(b.write('a'), &'a');
-        munge!(let Example(a, b,) = &mut mu);
-        assert_eq!(a.write(2), &2);
-        assert_eq!(b.write('b'), &'b');
+        struct Example {
+            a: u32,
+            b: Unit,
+            c: (char, Single),
+        }
 
-        munge!(let Example(a, _) = &mut mu);
-        assert_eq!(a.write(3), &3);
-        munge!(let Example(_, b) = &mut mu);
-        assert_eq!(b.write('c'), &'c');
-        munge!(let Example(a, _,) = &mut mu);
-        assert_eq!(a.write(3), &3);
-        munge!(let Example(_, b,) = &mut mu);
-        assert_eq!(b.write('c'), &'c');
+        let mut mu = MaybeUninit::<Example>::uninit();
+        munge!(
+            let Example {
+                a: ((x)),
+                b: Unit {},
+                c: ((c, Single::Only)),
+            } = &mut mu
+        );
+        x.write(1);
+        c.write('x');
 
-        munge!(let Example(a, ..) = &mut mu);
-        assert_eq!(a.write(4), &4);
+        let mut mu = MaybeUninit::<(u32, char)>::uninit();
+        munge!(let (a, b): (u32, char) = &mut mu);
+        a.write(2);
+        b.write('y');
+        // SAFETY: `mu` was completely initialized.
+        assert_eq!(unsafe { mu.assume_init() }, (2, 'y'));
+
+        let mut mu = MaybeUninit::<(u8, Unit)>::uninit();
+        Unit::first(&mut mu).write(3);
+
+        let cell = Cell::new(4u32);
+        let mut value = MaybeUninit::<char>::uninit();
+        munge!(let ((a, b)) = ((&cell, &mut value)));
+        assert_eq!(a.get(), 4);
+        b.write('z');
+    }
 
-        // SAFETY: `mu` is completely initialized.
-        let init = unsafe { mu.assume_init() };
-        assert_eq!(init.0, 4);
-        assert_eq!(init.1, 'c');
+    #[test]
+    // Lint attributes on field patterns are ignored before Rust 1.92.
+    #[rustversion::attr(before(1.92), allow(unused_mut))]
+    fn field_attributes() {
+        struct Example {
+            a: u32,
+            #[cfg(not(test))]
+            b: u8,
+            #[cfg(test)]
+            c: char,
+        }
 
-        let mut mu = MaybeUninit::<Example<Example<char>>>::uninit();
+        enum Variant {
+            Some {
+                a: u32,
+                #[cfg(not(test))]
+                b: u8,
+            },
+            #[allow(dead_code)]
+            None,
+        }
 
+        let mut mu = MaybeUninit::<Example>::uninit();
         munge!(
-            let Example::<Example<char>>(a, Example::<char>(b, c)) = &mut mu;
+            let Example {
+                a,
+                #[cfg(not(test))]
+                b,
+                #[cfg(test)]
+                #[allow(unused_mut)]
+                mut c,
+            } = &mut mu;
+            #[cfg(not(test))]
+            let Example { a: x, .. } = &mut mu;
         );
-        assert_eq!(a.write(1), &1);
-        assert_eq!(b.write(2), &2);
-        assert_eq!(c.write('a'), &'a');
+        a.write(1);
+        c.write('a');
+        // SAFETY: `mu` was completely initialized.
+        let init = unsafe { mu.assume_init() };
+        assert_eq!((init.a, init.c), (1, 'a'));
+
+        let mut md = ManuallyDrop::new(Variant::Some {
+            a: 2,
+            #[cfg(not(test))]
+            b: 3,
+        });
+        munge!(let Variant::Some { a, #[cfg(not(test))] b } = &mut md else {
+            panic!()
+        });
+        **a = 4;
+        let Variant::Some { a, .. } = *md else {
+            panic!();
+        };
+        assert_eq!(a, 4);
 
-        // SAFETY: `mu` is completely initialized.
+        let mut mu = MaybeUninit::new((5u32, 6u8));
+        munge!(#[cfg(test)] let (x, _) = &mut mu);
+        // SAFETY: `mu` was initialized.
+        assert_eq!(unsafe { x.assume_init() }, 5);
+    }
+
+    #[test]
+    fn wildcard_field_attributes() {
+        struct Example {
+            a: u32,
+            b: (u8, u8),
+        }
+
+        let mut mu = MaybeUninit::<Example>::uninit();
+        munge!(let Example { a, #[cfg(any())] b: _, .. } = &mut mu);
+        a.write(1);
+        munge!(let Example { #[cfg(any())] b: (_, _), .. } = &mut mu);
+        munge!(let Example { #[cfg(test)] a: _, b } = &mut mu);
+        b.write((2, 3));
+        // SAFETY: `mu` was completely initialized.
         let init = unsafe { mu.assume_init() };
-        assert_eq!(init.0, 1);
-        assert_eq!(init.1 .0, 2);
-        assert_eq!(init.1 .1, 'a');
+        assert_eq!((init.a, init.b), (1, (2, 3)));
+
+        let mut md = ManuallyDrop::new(Some(Example { a: 4, b: (5, 6) }));
+        munge!(
+            let Some(Example { a, #[cfg(any())] b: (_, _), .. }) = &mut md
+            else {
+                panic!()
+            }
+        );
+        **a = 7;
+        munge!(
+            let Some(Example { #[cfg(test)] a: _, b: (_, c) }) = &mut md else {
+                panic!()
+            }
+        );
+        **c = 8;
+        let Some(Example { a, b }) = &*md else {
+            panic!();
+        };
+        assert_eq!((*a, *b), (7, (5, 8)));
     }
 
     #[test]
-    fn project_nested_struct() {
+    #[allow(non_snake_case)]
+    fn uppercase_bindings() {
+        let mut mu = MaybeUninit::<(u32, char)>::uninit();
+        munge!(let (A, b) = &mut mu);
+        A.write(1);
+        b.write('a');
+        // SAFETY: `mu` was completely initialized.
+        assert_eq!(unsafe { mu.assume_init() }, (1, 'a'));
+
+        let cell = Cell::new((2, 'b'));
+        let (A, B) = munge!(&cell => (A, B));
+        assert_eq!((A.get(), B.get()), (2, 'b'));
+    }
+
+    #[test]
+    fn binding_modes() {
+        let mut mu = MaybeUninit::new((1u32, 2u8, 3u16));
+        munge!(let (a, ref b, raw!(c)) = &mut mu);
+        a.write(4);
+        let b: &MaybeUninit<u8> = b;
+        let c: *mut MaybeUninit<u16> = c;
+        // SAFETY: `c` points to a field of `mu` which is not otherwise
+        // borrowed.
+        unsafe {
+            assert_eq!(b.assume_init(), 2);
+            c.write(MaybeUninit::new(5));
+        }
+        // SAFETY: `mu` was initialized.
+        assert_eq!(unsafe { mu.assume_init() }, (4, 2, 5));
+
+        let mut cell = Cell::new((1u32, 2u32));
+        munge!(let (ref a, b) = &mut cell);
+        let a: &Cell<u32> = a;
+        b.set(3);
+        a.set(a.get() + b.get());
+        assert_eq!(cell.get(), (4, 3));
+
+        let mut md = ManuallyDrop::new(Some((1u32, 2u32)));
+        munge!(let Some((ref x, raw!(y))) = &mut md else { unreachable!() });
+        assert_eq!(**x, 1);
+        let y: *mut ManuallyDrop<u32> = y;
+        // SAFETY: `y` points to a field of `md` which is not otherwise
+        // borrowed.
+        unsafe { **y = 3 };
+        assert_eq!(*md, Some((1, 3)));
+    }
+
+    #[test]
+    fn init() {
         struct Inner {
             a: u32,
-            b: char,
+            b: Option<&'static str>,
         }
+
+        // SAFETY: `Inner` only contains an integer and an optional reference,
+        // which are valid when zeroed.
+        unsafe impl crate::Zeroable for Inner {}
+
+        struct Wrapper(u8, [Inner; 2]);
+
         struct Outer {
             inner: Inner,
-            c: i32,
+            wrapper: Wrapper,
+            pair: (char, bool),
+        }
+
+        #[repr(C)]
+        union Bits {
+            int: u32,
+            float: f32,
         }
 
+        let mut order = 0;
+        let mut next = || {
+            order += 1;
+            order
+        };
+
         let mut mu = MaybeUninit::<Outer>::uninit();
+        let outer = init!(&mut mu => Outer {
+            inner: Inner { a: next(), b: Some("a") },
+            wrapper: Wrapper {
+                0: 2,
+                1: [Inner { a: next(), ..zeroed }, Inner { ..zeroed }],
+            },
+            pair: ('x', (true)),
+        });
+        assert_eq!(outer.inner.a, 1);
+        assert_eq!(outer.inner.b, Some("a"));
+        assert_eq!(outer.wrapper.0, 2);
+        assert_eq!(outer.wrapper.1[0].a, 2);
+        assert_eq!(outer.wrapper.1[0].b, None);
+        assert_eq!(outer.wrapper.1[1].a, 0);
+        assert_eq!(outer.pair, ('x', true));
+        outer.pair.1 = false;
+
+        let mut mu = MaybeUninit::<[Inner; 2]>::uninit();
+        let [first, second] = init!(&mut mu => [
+            Inner { b: Some("b"), ..zeroed },
+            Inner { a: 3, b: None },
+        ]);
+        assert_eq!((first.a, first.b), (0, Some("b")));
+        assert_eq!((second.a, second.b), (3, None));
+
+        let mut mu = MaybeUninit::<Bits>::uninit();
+        let bits = init!(&mut mu => Bits { #[union] float: 1.0 });
+        // SAFETY: `float` was initialized and has the same size as `int`.
+        assert_eq!(unsafe { bits.int }, 1.0f32.to_bits());
+    }
 
-        munge!(let Outer { inner: Inner { a, b }, c } = &mut mu);
-        assert_eq!(a.write(1), &1);
-        assert_eq!(b.write('a'), &'a');
-        assert_eq!(c.write(2), &2);
+    #[test]
+    fn slots() {
+        struct Wrapper(u8, [char; 2]);
 
-        // SAFETY: `mu` is completely initialized.
-        let init = unsafe { mu.assume_init() };
-        assert_eq!(init.inner.a, 1);
-        assert_eq!(init.inner.b, 'a');
-        assert_eq!(init.c, 2);
+        struct Example {
+            a: u32,
+            wrapper: Wrapper,
+            pair: (bool, &'static str),
+        }
+
+        let mut mu = MaybeUninit::<Example>::uninit();
+        let value = crate::slots(&mut mu, |uninit, f| {
+            munge!(
+                let Example { a, wrapper: Wrapper(b, [c, d]), pair } = uninit
+            );
+            let d = d.write('y');
+            let c = c.write('x');
+            let pair = pair.write((true, "pair"));
+            crate::finish!(f => Example {
+                a: a.write(1),
+                wrapper: Wrapper { 0: b.write(2), 1: [c, d] },
+                pair,
+            })
+        });
+        assert_eq!(value.a, 1);
+        assert_eq!(value.wrapper.0, 2);
+        assert_eq!(value.wrapper.1, ['x', 'y']);
+        assert_eq!(value.pair, (true, "pair"));
+        value.a = 3;
+        assert_eq!(value.a, 3);
+
+        let mut mu = MaybeUninit::<()>::uninit();
+        crate::slots(&mut mu, |uninit, f| {
+            munge!(let () = uninit);
+            crate::finish!(f => ())
+        });
     }
 
     #[test]
-    fn project_nested_tuple() {
-        let mut mu = MaybeUninit::<(u32, (char, u32))>::uninit();
+    fn slots_drop_written_fields() {
+        extern crate std;
 
-        munge!(let (a, (b, c)) = &mut mu);
-        assert_eq!(a.write(1), &1);
-        assert_eq!(b.write('a'), &'a');
-        assert_eq!(c.write(2), &2);
+        use std::{panic, vec::Vec};
 
-        // SAFETY: `mu` is completely initialized.
-        let init = unsafe { mu.assume_init() };
-        assert_eq!(init, (1, ('a', 2)));
+        struct Noisy<'a>(u8, &'a Cell<Vec<u8>>);
+
+        impl Drop for Noisy<'_> {
+            fn drop(&mut self) {
+                let mut dropped = self.1.take();
+                dropped.push(self.0);
+                self.1.set(dropped);
+            }
+        }
+
+        type Noisies<'a> = (Noisy<'a>, (Noisy<'a>, Noisy<'a>), Noisy<'a>);
+
+        let dropped = Cell::new(Vec::new());
+        let mut mu = MaybeUninit::<Noisies<'_>>::uninit();
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            crate::slots(&mut mu, |uninit, _| {
+                munge!(let (a, (b, c), _) = uninit);
+                let _c = c.write(Noisy(3, &dropped));
+                let _a = a.write(Noisy(1, &dropped));
+                let _b = b.write(Noisy(2, &dropped));
+                panic!("initialization failed");
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(dropped.take(), [2, 1, 3]);
+
+        crate::slots(&mut mu, |uninit, _| {
+            munge!(let (a, (b, _), d) = uninit);
+            let _a = a.write(Noisy(1, &dropped));
+            let _d = d.write(Noisy(4, &dropped));
+            drop(b.write(Noisy(2, &dropped)));
+        });
+        assert_eq!(dropped.take(), [2, 4, 1]);
+
+        let value = crate::slots(&mut mu, |uninit, f| {
+            munge!(let (a, (b, c), d) = uninit);
+            let (a, b) =
+                (a.write(Noisy(1, &dropped)), b.write(Noisy(2, &dropped)));
+            let (c, d) =
+                (c.write(Noisy(3, &dropped)), d.write(Noisy(4, &dropped)));
+            crate::finish!(f => (a, (b, c), d))
+        });
+        assert_eq!(dropped.take(), []);
+        assert_eq!(value.1 .1 .0, 3);
+        // SAFETY: `value` was initialized by `finish!`.
+        unsafe { core::ptr::drop_in_place(value) };
+        assert_eq!(dropped.take(), [1, 2, 3, 4]);
     }
 
     #[test]
-    fn project_nested_array() {
-        let mut mu = MaybeUninit::<[[u32; 2]; 2]>::uninit();
+    fn munge_const() {
+        struct Example {
+            a: u32,
+            b: (char, [u8; 4]),
+        }
 
-        munge!(let [a, [b, c]] = &mut mu);
-        assert_eq!(a.write([1, 2]), &[1, 2]);
-        assert_eq!(b.write(3), &3);
-        assert_eq!(c.write(4), &4);
+        #[repr(C)]
+        union Bits {
+            int: u32,
+            float: f32,
+        }
 
-        // SAFETY: `mu` is completely initialized.
-        let init = unsafe { mu.assume_init() };
-        assert_eq!(init, [[1, 2], [3, 4]]);
+        const fn init(mu: &mut MaybeUninit<Example>) {
+            munge_const!(let Example { a, b: (c, [first, .., last]) } = mu);
+            a.write(1);
+            c.write('a');
+            first.write(2);
+            last.write(3);
+            munge_const!(let Example { b: (_, [_, x, y, _]), .. } = mu);
+            x.write(4);
+            y.write(5);
+        }
+
+        const EXAMPLE: Example = {
+            let mut mu = MaybeUninit::uninit();
+            init(&mut mu);
+            // SAFETY: `mu` was completely initialized by `init`.
+            unsafe { mu.assume_init() }
+        };
+        assert_eq!(EXAMPLE.a, 1);
+        assert_eq!(EXAMPLE.b, ('a', [2, 4, 5, 3]));
+
+        const BITS: u32 = {
+            let mut mu = MaybeUninit::<Bits>::uninit();
+            munge_const!(let Bits { #[union] float: f } = &mut mu);
+            f.write(1.0);
+            // SAFETY: `float` was initialized and has the same size as `int`.
+            unsafe { mu.assume_init().int }
+        };
+        assert_eq!(BITS, 1.0f32.to_bits());
+
+        const fn second(
+            md: &mut ManuallyDrop<(u32, char)>,
+        ) -> &mut ManuallyDrop<char> {
+            munge_const!(let (_, b): (u32, char) = md);
+            b
+        }
+
+        let mut md = ManuallyDrop::new((1, 'a'));
+        **second(&mut md) = 'b';
+        assert_eq!(*md, (1, 'b'));
+    }
+
+    #[test]
+    fn munge_match() {
+        use core::pin::Pin;
+
+        enum Example {
+            A(u32, char),
+            B { a: u32, b: Option<char> },
+            C,
+        }
+
+        fn describe(md: &ManuallyDrop<Example>) -> u32 {
+            munge_match!(md {
+                Example::A(a, _) => **a,
+                Example::B { b: Some('x'), .. } => 100,
+                Example::B { a, b: Some(_) } => **a + 1,
+                Example::B { b: None, .. } | Example::C => 0,
+            })
+        }
+
+        assert_eq!(describe(&ManuallyDrop::new(Example::A(1, 'a'))), 1);
+        let b = Example::B { a: 2, b: Some('x') };
+        assert_eq!(describe(&ManuallyDrop::new(b)), 100);
+        let b = Example::B { a: 2, b: Some('y') };
+        assert_eq!(describe(&ManuallyDrop::new(b)), 3);
+        let b = Example::B { a: 2, b: None };
+        assert_eq!(describe(&ManuallyDrop::new(b)), 0);
+        assert_eq!(describe(&ManuallyDrop::new(Example::C)), 0);
+
+        let mut cell = Cell::new(Example::A(1, 'a'));
+        munge_match!(&mut cell {
+            Example::A(a, b) => {
+                a.set(2);
+                b.set('b');
+            }
+            _ => unreachable!(),
+        });
+        assert!(matches!(cell.into_inner(), Example::A(2, 'b')));
+
+        let md = ManuallyDrop::new(Some([1, 2, 3]));
+        let first = munge_match!(md {
+            Some([first, _, _]) => ManuallyDrop::into_inner(first),
+            None => 0,
+        });
+        assert_eq!(first, 1);
+
+        let mut md = ManuallyDrop::new(Example::B { a: 1, b: Some('a') });
+        let mut pinned = Pin::new(&mut md);
+        munge_match!(pinned.as_mut() {
+            Example::B { a, b: Some(b) } => {
+                **a.get_mut() = 2;
+                **b.get_mut() = 'b';
+            }
+            _ => unreachable!(),
+        });
+        assert_eq!(describe(&pinned), 3);
+        munge!(let Example::B { a, .. } = pinned.as_ref() else {
+            unreachable!();
+        });
+        assert_eq!(**a, 2);
     }
 
     #[test]
@@ -499,7 +2270,9 @@ mod tests {
         let mu = MaybeUninit::<(u32, char)>::new((10_000, 'x'));
 
         munge!(let (a, b) = mu);
+        // SAFETY: `a` and `b` were initialized from `mu`.
         assert_eq!(unsafe { a.assume_init() }, 10_000);
+        // SAFETY: `a` and `b` were initialized from `mu`.
         assert_eq!(unsafe { b.assume_init() }, 'x');
     }
 
@@ -588,38 +2361,352 @@ mod tests {
     }
 
     #[test]
-    fn moved_tuple_wildcard_pattern_is_dropped() {
+    fn moved_tuple_wildcard_pattern_is_dropped_at_end_of_scope() {
         let mut flag = false;
         let value = Cell::new((NoisyDrop { flag: &mut flag },));
 
-        munge!(let (_,) = value);
+        {
+            munge!(let (_,) = value);
+        }
 
         assert!(flag);
     }
 
     #[test]
-    fn moved_tuple_struct_wildcard_pattern_is_dropped() {
+    fn moved_tuple_struct_wildcard_pattern_is_dropped_at_end_of_scope() {
         struct Example<'a>(NoisyDrop<'a>);
 
         let mut flag = false;
         let value = Cell::new(Example(NoisyDrop { flag: &mut flag }));
 
-        munge!(let Example(_) = value);
+        {
+            munge!(let Example(_) = value);
+        }
 
         assert!(flag);
     }
 
     #[test]
-    fn moved_array_wildcard_pattern_is_dropped() {
+    fn moved_array_wildcard_pattern_is_dropped_at_end_of_scope() {
         let mut flag = false;
         let value = Cell::new([NoisyDrop { flag: &mut flag }]);
 
-        munge!(let [_] = value);
+        {
+            munge!(let [_] = value);
+        }
 
         assert!(flag);
     }
 
     #[test]
+    fn moved_wildcards_are_dropped_after_bindings_in_field_order() {
+        extern crate std;
+
+        use std::vec::Vec;
+
+        struct Noisy<'a>(u8, &'a Cell<Vec<u8>>);
+
+        impl Drop for Noisy<'_> {
+            fn drop(&mut self) {
+                let mut dropped = self.1.take();
+                dropped.push(self.0);
+                self.1.set(dropped);
+            }
+        }
+
+        struct Unit;
+
+        struct Example<'a> {
+            a: Noisy<'a>,
+            b: (Noisy<'a>, Unit, Noisy<'a>),
+            c: Noisy<'a>,
+        }
+
+        let dropped = Cell::new(Vec::new());
+        let example = || Example {
+            a: Noisy(1, &dropped),
+            b: (Noisy(2, &dropped), Unit, Noisy(3, &dropped)),
+            c: Noisy(4, &dropped),
+        };
+
+        // Native destructuring drops the bindings in reverse order first, then
+        // the rest of the partially moved value in field order.
+        {
+            let value = example();
+            let Example {
+                a: _,
+                b: (_b, Unit, _),
+                c: _c,
+            } = value;
+            assert_eq!(dropped.take(), []);
+        }
+        let native = dropped.take();
+        assert_eq!(native, [4, 2, 1, 3]);
+
+        {
+            let value = Cell::new(example());
+            munge!(let Example { a: _, b: (_b, Unit {}, _), c: _c } = value);
+            assert_eq!(dropped.take(), []);
+        }
+        assert_eq!(dropped.take(), native);
+
+        {
+            let value = Fragile::new(Some(example()), usize::MAX);
+            munge!(
+                let Some(Example { a: _, b: (_b, Unit, _), c: _c }) = value
+                else {
+                    unreachable!()
+                }
+            );
+            assert_eq!(dropped.take(), []);
+        }
+        assert_eq!(dropped.take(), native);
+    }
+
+    /// A wrapper which is destructured by move and panics while restructuring
+    /// one of its fields.
+    struct Fragile<T> {
+        value: ManuallyDrop<T>,
+        panic_at: usize,
+        restructured: Cell<usize>,
+    }
+
+    impl<T> Fragile<T> {
+        fn new(value: T, panic_at: usize) -> Self {
+            Self {
+                value: ManuallyDrop::new(value),
+                panic_at,
+                restructured: Cell::new(0),
+            }
+        }
+    }
+
+    // SAFETY:
+    // - `Fragile<T>` is destructured by move, so its `Destructuring` type is
+    //   `Move`.
+    // - `underlying` returns a pointer to its inner value, which is guaranteed
+    //   to be non-null, properly-aligned, and valid for reads.
+    unsafe impl<T> crate::Destructure for Fragile<T> {
+        type Underlying = T;
+        type Destructuring = crate::Move;
+
+        fn underlying(&mut self) -> *mut Self::Underlying {
+            &mut *self.value
+        }
+    }
+
+    // SAFETY: `restructure` moves the field out of the inner value before it
+    // may panic, so the field is dropped if it panics.
+    unsafe impl<T, U> crate::Restructure<U> for Fragile<T> {
+        type Restructured = U;
+
+        unsafe fn restructure(&self, ptr: *mut U) -> Self::Restructured {
+            // SAFETY: The caller has guaranteed that `ptr` points to a field
+            // of the inner value, which is moved out of exactly once.
+            let value = unsafe { ptr.read() };
+            let index = self.restructured.get();
+            self.restructured.set(index + 1);
+            if index == self.panic_at {
+                panic!("failed to restructure field {index}");
+            }
+            value
+        }
+    }
+
+    // SAFETY: The inner value of `Fragile<T>` is always initialized and is
+    // only accessed through `Fragile<T>`.
+    unsafe impl<T> crate::DiscriminantStable for Fragile<T> {
+        type Access = crate::Exclusive;
+    }
+
+    #[test]
+    fn moved_fields_are_dropped_when_restructuring_panics() {
+        extern crate std;
+
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        struct Example<'a> {
+            a: NoisyDrop<'a>,
+            b: (NoisyDrop<'a>, [NoisyDrop<'a>; 2]),
+        }
+
+        for panic_at in 0..4 {
+            let mut flags = [false; 4];
+            let [w, x, y, z] = &mut flags;
+            let value = Fragile::new(
+                Example {
+                    a: NoisyDrop { flag: w },
+                    b: (
+                        NoisyDrop { flag: x },
+                        [NoisyDrop { flag: y }, NoisyDrop { flag: z }],
+                    ),
+                },
+                panic_at,
+            );
+            let restructured = catch_unwind(AssertUnwindSafe(|| {
+                munge!(let Example { a, b: (b, [c, _]) } = value);
+                (a, b, c)
+            }));
+            assert!(restructured.is_err());
+            drop(restructured);
+            assert_eq!(flags, [true; 4]);
+        }
+
+        for panic_at in 0..3 {
+            let mut flags = [false; 3];
+            let [x, y, z] = &mut flags;
+            let value = Fragile::new(
+                Some((
+                    NoisyDrop { flag: x },
+                    NoisyDrop { flag: y },
+                    NoisyDrop { flag: z },
+                )),
+                panic_at,
+            );
+            let restructured = catch_unwind(AssertUnwindSafe(|| {
+                munge!(let Some((a, _, c)) = value else { unreachable!() });
+                (a, c)
+            }));
+            assert!(restructured.is_err());
+            drop(restructured);
+            assert_eq!(flags, [true; 3]);
+        }
+    }
+
+    #[test]
+    fn moved_rest_patterns_drop_unnamed_fields() {
+        extern crate std;
+
+        use std::{
+            panic::{catch_unwind, AssertUnwindSafe},
+            vec::Vec,
+        };
+
+        struct Noisy<'a>(u8, &'a Cell<Vec<u8>>);
+
+        impl Drop for Noisy<'_> {
+            fn drop(&mut self) {
+                let mut dropped = self.1.take();
+                dropped.push(self.0);
+                self.1.set(dropped);
+            }
+        }
+
+        #[derive(crate::DropRest)]
+        #[munge(crate = crate)]
+        struct Config<'a> {
+            name: Noisy<'a>,
+            size: Noisy<'a>,
+            tags: Noisy<'a>,
+        }
+
+        #[derive(crate::DropRest)]
+        #[munge(crate = crate)]
+        struct Tuple<'a>(Noisy<'a>, Noisy<'a>, Noisy<'a>);
+
+        let dropped = Cell::new(Vec::new());
+        let config = || Config {
+            name: Noisy(1, &dropped),
+            size: Noisy(2, &dropped),
+            tags: Noisy(3, &dropped),
+        };
+        let tuple = || {
+            Tuple(Noisy(1, &dropped), Noisy(2, &dropped), Noisy(3, &dropped))
+        };
+
+        {
+            munge!(let Config { size: _size, .. } = Cell::new(config()));
+            assert_eq!(dropped.take(), [1, 3]);
+        }
+        assert_eq!(dropped.take(), [2]);
+
+        {
+            munge!(let Tuple(_a, ..) = Cell::new(tuple()));
+            assert_eq!(dropped.take(), [2, 3]);
+        }
+        assert_eq!(dropped.take(), [1]);
+
+        {
+            munge!(
+                let Some(Tuple(_a, .., _c)) =
+                    Fragile::new(Some(tuple()), usize::MAX)
+                else {
+                    unreachable!()
+                }
+            );
+            assert_eq!(dropped.take(), [2]);
+        }
+        assert_eq!(dropped.take(), [3, 1]);
+
+        {
+            munge!(let Config { name, .. } = ManuallyDrop::new(config()));
+            assert_eq!(dropped.take(), []);
+            drop(ManuallyDrop::into_inner(name));
+        }
+        assert_eq!(dropped.take(), [1]);
+
+        // The rest of the fields are dropped if restructuring a named field
+        // panics.
+        let restructured = catch_unwind(AssertUnwindSafe(|| {
+            munge!(let Config { name, .. } = Fragile::new(config(), 0));
+            name
+        }));
+        assert!(restructured.is_err());
+        assert_eq!(dropped.take(), [1, 2, 3]);
+
+        // The fields after a rest field which panics are leaked.
+        let restructured = catch_unwind(AssertUnwindSafe(|| {
+            munge!(let Config { name, .. } = Fragile::new(config(), 1));
+            name
+        }));
+        assert!(restructured.is_err());
+        assert_eq!(dropped.take(), [2, 1]);
+    }
+
+    #[test]
+    fn moved_rest_patterns_between_tuple_fields() {
+        extern crate std;
+
+        use std::{
+            string::{String, ToString},
+            vec::Vec,
+        };
+
+        struct Named<'a>(String, &'a Cell<Vec<String>>);
+
+        impl Drop for Named<'_> {
+            fn drop(&mut self) {
+                let mut dropped = self.1.take();
+                dropped.push(self.0.clone());
+                self.1.set(dropped);
+            }
+        }
+
+        #[derive(crate::DropRest)]
+        #[munge(crate = crate)]
+        struct Tuple<'a>(Named<'a>, Named<'a>, Named<'a>, Named<'a>);
+
+        let dropped = Cell::new(Vec::new());
+        let named = |name: &str| Named(name.to_string(), &dropped);
+        let tuple = Tuple(named("a"), named("b"), named("c"), named("d"));
+
+        {
+            munge!(
+                let Some(Tuple(a, .., d)) =
+                    Fragile::new(Some(tuple), usize::MAX)
+                else {
+                    unreachable!()
+                }
+            );
+            assert_eq!(a.0, "a");
+            assert_eq!(d.0, "d");
+            assert_eq!(dropped.take(), ["b", "c"]);
+        }
+        assert_eq!(dropped.take(), ["d", "a"]);
+    }
+
+    #[test]
+    #[allow(clippy::unneeded_wildcard_pattern)]
     fn rest_in_full_tuple_pattern() {
         let (_, _, ..) = (1, 2);
 
@@ -0,0 +1,165 @@
+use core::{marker::PhantomData, mem::MaybeUninit};
+
+use crate::{Destructure, Move, Restructure};
+
+/// An invariant lifetime which ties slots and tokens to their storage.
+type Brand<'brand> = PhantomData<fn(&'brand ()) -> &'brand ()>;
+
+/// Initializes a `MaybeUninit` by writing each of its fields through a
+/// [`Slot`].
+///
+/// `slots(mu, |uninit, finish| ...)` calls the closure with an [`Uninit`] that
+/// can be destructured into slots for the fields of `mu`, and a [`Finish`]
+/// that returns a mutable reference to the initialized value once every slot
+/// has been written. Writing a slot returns a [`Written`] token, and handing
+/// the tokens to [`finish!`](crate::finish) in the shape of the pattern proves
+/// that every field was written.
+///
+/// The slots and tokens are branded with a lifetime that is unique to this
+/// call, so tokens from any other storage can't be used to finish it.
+///
+/// # Example
+///
+/// ```
+/// # use core::mem::MaybeUninit;
+/// # use munge::{finish, munge, slots};
+/// pub struct Example {
+///     a: u32,
+///     b: (char, f32),
+/// }
+///
+/// let mut mu = MaybeUninit::<Example>::uninit();
+/// let value = slots(&mut mu, |uninit, f| {
+///     munge!(let Example { a, b: (c, d) } = uninit);
+///     let a = a.write(1);
+///     let c = c.write('x');
+///     let d = d.write(3.14);
+///     finish!(f => Example { a, b: (c, d) })
+/// });
+///
+/// assert_eq!(value.a, 1);
+/// assert_eq!(value.b, ('x', 3.14));
+/// ```
+pub fn slots<'a, T, R>(
+    value: &'a mut MaybeUninit<T>,
+    f: impl for<'brand> FnOnce(Uninit<'brand, T>, Finish<'brand, 'a, T>) -> R,
+) -> R {
+    let ptr = value.as_mut_ptr();
+    f(
+        Uninit {
+            ptr,
+            _brand: PhantomData,
+        },
+        Finish {
+            ptr,
+            _brand: PhantomData,
+            _phantom: PhantomData,
+        },
+    )
+}
+
+/// Uninitialized storage which may be destructured into [`Slot`]s.
+///
+/// `Uninit` is destructured by move, so it can only be destructured once. See
+/// [`slots`] for more details.
+pub struct Uninit<'brand, T> {
+    ptr: *mut T,
+    _brand: Brand<'brand>,
+}
+
+// SAFETY:
+// - `Uninit<'brand, T>` is destructured by move, so its `Destructuring` type is
+//   `Move`.
+// - `underlying` returns the pointer to the storage passed to `slots`, which is
+//   non-null, properly aligned, and valid for reads.
+unsafe impl<T> Destructure for Uninit<'_, T> {
+    type Underlying = T;
+    type Destructuring = Move;
+
+    fn underlying(&mut self) -> *mut Self::Underlying {
+        self.ptr
+    }
+}
+
+// SAFETY: `restructure` returns a `Slot<'brand, U>` that takes ownership of
+// the restructured field because `Uninit<'brand, T>` is destructured by move.
+unsafe impl<'brand, T, U> Restructure<U> for Uninit<'brand, T> {
+    type Restructured = Slot<'brand, U>;
+
+    unsafe fn restructure(&self, ptr: *mut U) -> Self::Restructured {
+        Slot {
+            ptr,
+            _brand: PhantomData,
+        }
+    }
+}
+
+/// An uninitialized field of the storage passed to [`slots`].
+pub struct Slot<'brand, T> {
+    ptr: *mut T,
+    _brand: Brand<'brand>,
+}
+
+impl<'brand, T> Slot<'brand, T> {
+    /// Writes a value to the field, returning a token which proves that the
+    /// field was written.
+    pub fn write(self, value: T) -> Written<'brand, T> {
+        // SAFETY: `ptr` points to a field of the storage passed to `slots`,
+        // which is mutably borrowed for the duration of the call and is not
+        // aliased by any other slot.
+        unsafe { self.ptr.write(value) };
+        Written {
+            ptr: self.ptr,
+            _brand: PhantomData,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// A token which proves that a [`Slot`] was written.
+///
+/// Tokens can't be copied or cloned, and each slot produces at most one token.
+///
+/// A token also guards the value written to its field: dropping the token
+/// drops the value in place. If initialization panics or returns without
+/// finishing, each field that was written is dropped when its token is
+/// dropped.
+/// [`finish!`](crate::finish) disarms the tokens once every field has been
+/// written.
+pub struct Written<'brand, T> {
+    ptr: *mut T,
+    _brand: Brand<'brand>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> Drop for Written<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: `ptr` points to a field of the storage passed to `slots`
+        // which was initialized by `write`. The token is the only way to
+        // access the field, so the field is dropped exactly once.
+        unsafe { self.ptr.drop_in_place() }
+    }
+}
+
+/// Returns a mutable reference to the storage passed to [`slots`] once every
+/// field has been written.
+///
+/// Use [`finish!`](crate::finish) to finish initializing the storage.
+pub struct Finish<'brand, 'a, T> {
+    ptr: *mut T,
+    _brand: Brand<'brand>,
+    _phantom: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Finish<'_, 'a, T> {
+    /// Returns a mutable reference to the initialized storage.
+    ///
+    /// # Safety
+    ///
+    /// Every field of the storage must have been written.
+    pub(crate) unsafe fn finish_unchecked(self) -> &'a mut T {
+        // SAFETY: The caller has guaranteed that every field of the storage
+        // has been written, and the storage is mutably borrowed for `'a`.
+        unsafe { &mut *self.ptr }
+    }
+}
@@ -0,0 +1,49 @@
+// Every kind of `syn::Pat` which munge supports. Unsupported kinds are covered
+// by the `no_*_patterns` UI tests.
+#![allow(unused_parens)]
+
+use core::{cell::Cell, mem::ManuallyDrop, mem::MaybeUninit};
+use munge::{munge, munge_match};
+
+struct Unit;
+
+struct Example {
+    a: u32,
+    b: (char, Unit),
+    c: [u8; 4],
+}
+
+struct Wrapper(u32, u32);
+
+fn main() {
+    let mut mu = MaybeUninit::<Example>::uninit();
+    munge!(
+        // Struct, Ident, Tuple, Paren, Path, Slice, Rest, and Wild
+        let Example { a, b: ((c, Unit)), c: [first, .., _] } = &mut mu
+    );
+    a.write(1);
+    c.write('x');
+    first.write(2);
+
+    // TupleStruct, Type, and Macro (`raw!`)
+    let mut mu = MaybeUninit::<Wrapper>::uninit();
+    munge!(let Wrapper(x, raw!(y)): Wrapper = &mut mu);
+    x.write(3);
+    let _: *mut MaybeUninit<u32> = y;
+
+    // Lit, Range, and Or
+    let md = ManuallyDrop::new((1u32, Some(2u8)));
+    munge_match!(&md {
+        (0, _) => {}
+        (1..=5, None) => {}
+        (6, Some(0 | 1)) => {}
+        (_, Some(value)) => {
+            let _: &ManuallyDrop<u8> = value;
+        }
+        _ => {}
+    });
+
+    let cell = Cell::new((1u32, 2u32));
+    munge!(let (ref a, _) = &cell);
+    let _: &Cell<u32> = a;
+}
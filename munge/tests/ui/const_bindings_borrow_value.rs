@@ -0,0 +1,9 @@
+use core::mem::MaybeUninit;
+use munge::munge_const;
+
+const fn first(mu: &mut MaybeUninit<(u32, u32)>) -> &'static mut MaybeUninit<u32> {
+    munge_const!(let (a, _) = mu);
+    a
+}
+
+fn main() {}
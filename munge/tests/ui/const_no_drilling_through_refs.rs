@@ -0,0 +1,12 @@
+use core::mem::MaybeUninit;
+use munge::munge_const;
+
+pub struct Example<'a> {
+    a: &'a (u32, u32),
+}
+
+const fn init(mu: &mut MaybeUninit<Example<'_>>) {
+    munge_const!(let Example { a: (x, y) } = mu);
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use core::mem::MaybeUninit;
+use munge::munge_const;
+
+const fn first(mu: &mut MaybeUninit<(u32, u32)>) {
+    munge_const!(let (ref a, raw!(b)) = mu);
+}
+
+fn main() {}
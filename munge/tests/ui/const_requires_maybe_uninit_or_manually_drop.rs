@@ -0,0 +1,8 @@
+use core::cell::Cell;
+use munge::munge_const;
+
+const fn first(cell: &mut Cell<(u32, u32)>) {
+    munge_const!(let (a, _) = cell);
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use munge::DropRest;
+
+#[derive(DropRest)]
+#[repr(C, packed)]
+struct Packed {
+    a: u8,
+    b: u32,
+}
+
+#[derive(DropRest)]
+enum Enum {
+    A(u32),
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use core::mem::ManuallyDrop;
+use munge::{munge, Emplace};
+
+#[derive(Emplace)]
+#[repr(u8)]
+enum Example {
+    A(u32),
+    B(String),
+}
+
+fn main() {
+    let mut md = ManuallyDrop::new(Example::B(String::new()));
+    munge!(let Example::A(a) = emplace &mut md);
+    **a = 1;
+}
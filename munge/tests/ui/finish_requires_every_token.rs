@@ -0,0 +1,27 @@
+use core::mem::MaybeUninit;
+
+use munge::{finish, munge, slots};
+
+struct Example {
+    a: u32,
+    b: u32,
+}
+
+fn main() {
+    let mut mu = MaybeUninit::<(u32, (u8, char))>::uninit();
+    slots(&mut mu, |uninit, f| {
+        munge!(let (a, (b, c)) = uninit);
+        let a = a.write(1);
+        let b = b.write(2);
+        drop(c);
+        finish!(f => (a, b))
+    });
+
+    let mut mu = MaybeUninit::<Example>::uninit();
+    slots(&mut mu, |uninit, f| {
+        munge!(let Example { a, b } = uninit);
+        let a = a.write(1);
+        drop(b);
+        finish!(f => Example { a, ..zeroed })
+    });
+}
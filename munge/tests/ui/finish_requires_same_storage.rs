@@ -0,0 +1,18 @@
+use core::mem::MaybeUninit;
+
+use munge::{finish, munge, slots};
+
+fn main() {
+    let mut first = MaybeUninit::<(u32, u32)>::uninit();
+    let mut second = MaybeUninit::<(u32, u32)>::uninit();
+    slots(&mut first, |uninit, f| {
+        munge!(let (a, b) = uninit);
+        let a = a.write(1);
+        drop(b);
+        slots(&mut second, |uninit, _| {
+            munge!(let (c, _d) = uninit);
+            let c = c.write(2);
+            finish!(f => (a, c))
+        })
+    });
+}
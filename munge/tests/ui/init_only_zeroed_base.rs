@@ -0,0 +1,13 @@
+use core::mem::MaybeUninit;
+use munge::init;
+
+#[derive(Default)]
+pub struct Example {
+    a: u32,
+    b: u32,
+}
+
+fn main() {
+    let mut mu = MaybeUninit::<Example>::uninit();
+    init!(&mut mu => Example { a: 1, ..Default::default() });
+}
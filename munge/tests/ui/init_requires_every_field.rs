@@ -0,0 +1,16 @@
+use core::mem::MaybeUninit;
+use munge::init;
+
+pub struct Example {
+    a: u32,
+    b: (u8, char),
+    c: [u16; 2],
+}
+
+fn main() {
+    let mut mu = MaybeUninit::<Example>::uninit();
+    init!(&mut mu => Example { a: 1, b: (2, 'c'), c: [3, 4] });
+    init!(&mut mu => Example { a: 1, b: (2, 'c') });
+    init!(&mut mu => Example { a: 1, b: (2,), c: [3, 4] });
+    init!(&mut mu => Example { a: 1, b: (2, 'c'), c: [3] });
+}
@@ -0,0 +1,12 @@
+use core::mem::MaybeUninit;
+use munge::init;
+
+pub struct Example {
+    a: u32,
+    b: &'static str,
+}
+
+fn main() {
+    let mut mu = MaybeUninit::<Example>::uninit();
+    init!(&mut mu => Example { a: 1, ..zeroed });
+}
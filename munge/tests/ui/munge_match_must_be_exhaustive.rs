@@ -0,0 +1,9 @@
+use core::mem::ManuallyDrop;
+use munge::munge_match;
+
+fn main() {
+    let mut md = ManuallyDrop::new(Some((1u32, 2u32)));
+    munge_match!(&mut md {
+        Some((_, b)) => **b = 3,
+    });
+}
@@ -0,0 +1,7 @@
+use core::mem::MaybeUninit;
+use munge::munge;
+
+fn main() {
+    let mut mu = MaybeUninit::<(u32, u32)>::uninit();
+    munge!(let (box a, _) = &mut mu);
+}
@@ -0,0 +1,9 @@
+use core::cell::Cell;
+use munge::munge;
+
+fn main() {
+    let cell = Cell::new(Some(1u32));
+    munge!(let Some(x) = &cell else { return });
+    cell.set(None);
+    let _ = x.get();
+}
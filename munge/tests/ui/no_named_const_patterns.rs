@@ -0,0 +1,9 @@
+use core::mem::MaybeUninit;
+use munge::munge;
+
+const ZERO: u32 = 0;
+
+fn main() {
+    let mut mu = MaybeUninit::<(u32, u32)>::uninit();
+    munge!(let (ZERO, b) = &mut mu);
+}
@@ -0,0 +1,8 @@
+use core::mem::MaybeUninit;
+use munge::munge;
+
+fn main() {
+    let mu = MaybeUninit::<(u32, u32)>::uninit();
+    munge!(let (ref a, b) = mu);
+    let _ = (a, b);
+}
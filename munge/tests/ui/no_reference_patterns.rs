@@ -0,0 +1,11 @@
+use core::cell::Cell;
+use core::mem::ManuallyDrop;
+use munge::munge;
+
+fn main() {
+    let cell = Cell::new((1u32, 2u32));
+    munge!(let (&a, _) = &cell);
+
+    let mut md = ManuallyDrop::new(Some((1u32, 2u32)));
+    munge!(let Some((&mut a, _)) = &mut md else { return; });
+}
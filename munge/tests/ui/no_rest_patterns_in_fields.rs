@@ -0,0 +1,12 @@
+use core::mem::MaybeUninit;
+use munge::munge;
+
+struct Example {
+    a: u32,
+    b: u32,
+}
+
+fn main() {
+    let mut mu = MaybeUninit::<Example>::uninit();
+    munge!(let Example { a, b: .. } = &mut mu);
+}
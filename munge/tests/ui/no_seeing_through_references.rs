@@ -0,0 +1,15 @@
+use core::mem::{ManuallyDrop, MaybeUninit};
+use munge::munge;
+
+fn main() {
+    struct Example {
+        a: u32,
+        b: u32,
+    }
+
+    let mut mu = MaybeUninit::<Example>::uninit();
+    let mut md = ManuallyDrop::new(&mut mu);
+    munge!(let Example { a, b } = &mut md);
+    a.write(1);
+    b.write(2);
+}
@@ -0,0 +1,9 @@
+use core::mem::MaybeUninit;
+use munge::munge;
+
+fn main() {
+    let mut mu = MaybeUninit::<(u32, (u32, u32))>::uninit();
+    munge!(let (_, pair @ (x, _)) = &mut mu);
+    x.write(1);
+    pair.write((2, 3));
+}
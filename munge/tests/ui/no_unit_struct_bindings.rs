@@ -0,0 +1,9 @@
+use core::mem::MaybeUninit;
+use munge::munge;
+
+struct Unit;
+
+fn main() {
+    let mut mu = MaybeUninit::<(u32, Unit)>::uninit();
+    munge!(let (a, Unit) = &mut mu);
+}
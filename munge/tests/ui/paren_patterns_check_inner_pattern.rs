@@ -0,0 +1,8 @@
+use core::mem::MaybeUninit;
+use munge::munge;
+
+#[allow(unused_parens)]
+fn main() {
+    let mut mu = MaybeUninit::<(u32, u32)>::uninit();
+    munge!(let (a, ((1))) = &mut mu);
+}
@@ -0,0 +1,11 @@
+use core::mem::MaybeUninit;
+use munge::munge;
+
+enum Single {
+    Only,
+}
+
+fn main() {
+    let mut mu = MaybeUninit::<(u32, u32)>::uninit();
+    munge!(let (a, Single::Only) = &mut mu);
+}
@@ -0,0 +1,12 @@
+use core::mem::MaybeUninit;
+use munge::munge;
+
+fn main() {
+    let mut mu = MaybeUninit::<(u32, (u32, u32), char)>::uninit();
+    munge!(let (a, (&b, 0), ref mut c) = &mut mu);
+    a.write(1);
+    b.write(2);
+    c.write('x');
+    // The placeholder for `b` has the type of its field.
+    let _: () = b;
+}
@@ -0,0 +1,12 @@
+use core::{cell::Cell, mem::MaybeUninit};
+use munge::munge;
+
+struct Tuple(String, String, String);
+
+fn main() {
+    let mut mu = MaybeUninit::<(String, String, String)>::uninit();
+    munge!(let (_a, .., _c) = &mut mu);
+
+    let value = Cell::new(Tuple(String::new(), String::new(), String::new()));
+    munge!(let Tuple(_a, .., _c) = &value);
+}
@@ -0,0 +1,12 @@
+use core::mem::MaybeUninit;
+
+use munge::{munge, slots};
+
+fn main() {
+    let mut mu = MaybeUninit::<(u32, u32)>::uninit();
+    slots(&mut mu, |uninit, _| {
+        munge!(let (a, _) = uninit);
+        munge!(let (b, _) = uninit);
+        let _ = (a.write(1), b.write(2));
+    });
+}
@@ -0,0 +1,9 @@
+use core::mem::MaybeUninit;
+use munge::munge;
+
+fn main() {
+    let mut mu = MaybeUninit::<(u32, u16)>::uninit();
+    munge!(let (a, b): (u32, u8) = &mut mu);
+    a.write(1);
+    b.write(2);
+}
@@ -9,15 +9,25 @@
     rustdoc::missing_crate_level_docs
 )]
 
-use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned};
+use std::borrow::Cow;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::{
-    parse, parse_macro_input,
+    braced, bracketed,
+    ext::IdentExt as _,
+    parse,
+    parse::{discouraged::Speculative, Parser as _},
+    parse_macro_input, parse_quote,
     punctuated::Punctuated,
     spanned::Spanned,
-    token::{Eq, FatArrow, Let, Semi},
-    Error, Expr, FieldPat, Index, Pat, PatIdent, PatRest, PatSlice, PatStruct,
-    PatTuple, PatTupleStruct, Path,
+    token::{Bracket, Eq, FatArrow, Let, Semi},
+    visit::{self, Visit},
+    visit_mut::{self, VisitMut},
+    Arm, Attribute, Block, Error, Expr, ExprClosure, ExprStruct, FieldPat,
+    FnArg, Index, ItemEnum, ItemFn, ItemStruct, Member, Pat, PatIdent,
+    PatMacro, PatParen, PatRest, PatSlice, PatStruct, PatTuple, PatTupleStruct,
+    PatType, Path, RangeLimits, Token,
 };
 
 /// Destructures a value by projecting pointers.
@@ -31,10 +41,95 @@ pub fn munge_with_path(
         .into()
 }
 
+/// Destructures a value by projecting pointers in a constant context.
+#[proc_macro]
+pub fn munge_const_with_path(
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as Input);
+    destructure_const(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Initializes a value in place from a struct literal.
+#[proc_macro]
+pub fn init_with_path(
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as InitInput);
+    init(input).unwrap_or_else(|e| e.to_compile_error()).into()
+}
+
+/// Finishes initializing a value from tokens proving that each field was
+/// written.
+#[proc_macro]
+pub fn finish_with_path(
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as InitInput);
+    finish(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Destructures the parameters of a function marked with `#[munge]` using
+/// munge.
+#[proc_macro_attribute]
+pub fn params(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let attr = parse_macro_input!(attr as ParamsAttr);
+    let item = parse_macro_input!(item as ItemFn);
+    destructure_params(attr, item)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives `DropRest` for a struct.
+#[proc_macro_derive(DropRest, attributes(munge))]
+pub fn derive_drop_rest(
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let item = parse_macro_input!(input as ItemStruct);
+    derive_drop_rest_impl(item)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives `Emplace` for an enum with a primitive representation.
+#[proc_macro_derive(Emplace, attributes(munge))]
+pub fn derive_emplace(
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let item = parse_macro_input!(input as ItemEnum);
+    derive_emplace_impl(item)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+struct ParamsAttr {
+    crate_path: Path,
+}
+
+impl parse::Parse for ParamsAttr {
+    fn parse(input: parse::ParseStream) -> parse::Result<Self> {
+        let crate_path = if input.is_empty() {
+            parse_quote! { ::munge }
+        } else {
+            input.parse::<Token![crate]>()?;
+            input.parse::<Eq>()?;
+            input.parse::<Path>()?
+        };
+        Ok(ParamsAttr { crate_path })
+    }
+}
+
 struct Input {
     crate_path: Path,
     _arrow: FatArrow,
-    destructures: Punctuated<Destructure, Semi>,
+    body: Body,
 }
 
 impl parse::Parse for Input {
@@ -42,29 +137,223 @@ impl parse::Parse for Input {
         Ok(Input {
             crate_path: input.parse::<Path>()?,
             _arrow: input.parse::<FatArrow>()?,
-            destructures: input.parse_terminated(Destructure::parse, Semi)?,
+            body: input.parse::<Body>()?,
+        })
+    }
+}
+
+/// The input to `init!`, like `&mut mu => Example { a: 1, ..zeroed }`.
+struct InitInput {
+    crate_path: Path,
+    _arrow: FatArrow,
+    expr: Expr,
+    _init_arrow: FatArrow,
+    init: Expr,
+}
+
+impl parse::Parse for InitInput {
+    fn parse(input: parse::ParseStream) -> parse::Result<Self> {
+        Ok(InitInput {
+            crate_path: input.parse::<Path>()?,
+            _arrow: input.parse::<FatArrow>()?,
+            expr: input.parse::<Expr>()?,
+            _init_arrow: input.parse::<FatArrow>()?,
+            init: input.parse::<Expr>()?,
         })
     }
 }
 
+enum Body {
+    /// A sequence of `let` destructures which introduce bindings.
+    Destructures(Punctuated<Destructure, Semi>),
+    /// An `expr => pat` destructure which evaluates to its bindings.
+    Expr(Box<Expr>, Lift),
+    /// A `match expr { arms }` destructure which branches on refutable
+    /// patterns.
+    Match(Box<Expr>, Vec<Arm>),
+}
+
+impl parse::Parse for Body {
+    fn parse(input: parse::ParseStream) -> parse::Result<Self> {
+        if input.peek(Let) || input.peek(Token![#]) {
+            Ok(Body::Destructures(
+                input.parse_terminated(Destructure::parse, Semi)?,
+            ))
+        } else if input.peek(Token![match]) {
+            input.parse::<Token![match]>()?;
+            let expr = Expr::parse_without_eager_brace(input)?;
+            let content;
+            braced!(content in input);
+            let mut arms = Vec::new();
+            while !content.is_empty() {
+                let arm = content.parse::<Arm>()?;
+                if let Some((if_token, _)) = &arm.guard {
+                    return Err(Error::new_spanned(
+                        if_token,
+                        "match guards are not supported in munge destructures",
+                    ));
+                }
+                arms.push(arm);
+            }
+            if !input.is_empty() {
+                return Err(input.error("unexpected tokens after match arms"));
+            }
+            Ok(Body::Match(Box::new(expr), arms))
+        } else {
+            let expr = input.parse::<Expr>()?;
+            let lift = input.parse::<Lift>()?;
+            if !input.is_empty() {
+                return Err(input.error("unexpected tokens after pattern"));
+            }
+            Ok(Body::Expr(Box::new(expr), lift))
+        }
+    }
+}
+
 struct Destructure {
+    attrs: Vec<Attribute>,
     _let_token: Let,
     pat: Pat,
+    columns: Option<Columns>,
     _eq_token: Eq,
+    emplace: Option<kw::emplace>,
     expr: Expr,
+    lift: Option<Lift>,
+    else_block: Option<(Token![else], Block)>,
+}
+
+mod kw {
+    syn::custom_keyword!(emplace);
 }
 
 impl parse::Parse for Destructure {
     fn parse(input: parse::ParseStream) -> parse::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let let_token = input.parse::<Let>()?;
+
+        let fork = input.fork();
+        let (pat, columns) = if let Ok((pat, columns)) = Columns::parse(&fork) {
+            input.advance_to(&fork);
+            (pat, Some(columns))
+        } else {
+            let mut pat = Pat::parse_single(input)?;
+            if input.peek(Token![:]) {
+                pat = Pat::Type(PatType {
+                    attrs: Vec::new(),
+                    pat: Box::new(pat),
+                    colon_token: input.parse()?,
+                    ty: input.parse()?,
+                });
+            }
+            (pat, None)
+        };
+
+        let eq_token = input.parse::<Eq>()?;
+        let emplace = if input.peek(kw::emplace)
+            && (input.peek2(Token![&]) || input.peek2(syn::Ident))
+        {
+            let emplace = input.parse::<kw::emplace>()?;
+            if columns.is_some() {
+                return Err(Error::new_spanned(
+                    emplace,
+                    "a destructure may not both destructure columns and \
+                     emplace an enum variant",
+                ));
+            }
+            Some(emplace)
+        } else {
+            None
+        };
+        let expr = input.parse::<Expr>()?;
+        let lift = if input.peek(FatArrow) {
+            let lift = input.parse::<Lift>()?;
+            if columns.is_some() || emplace.is_some() {
+                return Err(Error::new_spanned(
+                    &lift.pat,
+                    "a destructure may not evaluate to its bindings while \
+                     destructuring columns or emplacing an enum variant",
+                ));
+            }
+            Some(lift)
+        } else {
+            None
+        };
+        let else_block = if input.peek(Token![else]) {
+            let else_token = input.parse::<Token![else]>()?;
+            if columns.is_some() || lift.is_some() || emplace.is_some() {
+                return Err(Error::new_spanned(
+                    else_token,
+                    "a destructure with an `else` block may not destructure \
+                     columns, emplace an enum variant, or evaluate to its \
+                     bindings",
+                ));
+            }
+            Some((else_token, input.parse::<Block>()?))
+        } else {
+            None
+        };
+
         Ok(Destructure {
-            _let_token: input.parse::<Let>()?,
+            attrs,
+            _let_token: let_token,
+            pat,
+            columns,
+            _eq_token: eq_token,
+            emplace,
+            expr,
+            lift,
+            else_block,
+        })
+    }
+}
+
+/// The `=> pat` suffix of a destructure, which destructures a value with `pat`
+/// and evaluates to its bindings. Values inside of an `Option` or `Result` are
+/// destructured and their bindings are lifted back into the container.
+struct Lift {
+    _arrow: FatArrow,
+    pat: Pat,
+}
+
+impl parse::Parse for Lift {
+    fn parse(input: parse::ParseStream) -> parse::Result<Self> {
+        Ok(Lift {
+            _arrow: input.parse::<FatArrow>()?,
             pat: Pat::parse_single(input)?,
-            _eq_token: input.parse::<Eq>()?,
-            expr: input.parse::<Expr>()?,
         })
     }
 }
 
+/// The `[pat; len]` form of a destructure, which destructures each element of
+/// an array with `pat` and collects each binding into an array.
+struct Columns {
+    _bracket_token: Bracket,
+    _semi_token: Semi,
+    len: Expr,
+}
+
+impl Columns {
+    fn parse(input: parse::ParseStream) -> parse::Result<(Pat, Self)> {
+        let content;
+        let bracket_token = bracketed!(content in input);
+        let pat = Pat::parse_single(&content)?;
+        let semi_token = content.parse::<Semi>()?;
+        let len = content.parse::<Expr>()?;
+        if !content.is_empty() {
+            return Err(content.error("unexpected tokens after array length"));
+        }
+
+        Ok((
+            pat,
+            Columns {
+                _bracket_token: bracket_token,
+                _semi_token: semi_token,
+                len,
+            },
+        ))
+    }
+}
+
 fn make_rest_check(crate_path: &Path, rest: &PatRest) -> TokenStream {
     let span = rest.dot2_token.span();
     let destructurer = quote! { destructurer };
@@ -80,9 +369,148 @@ fn make_rest_check(crate_path: &Path, rest: &PatRest) -> TokenStream {
     } }
 }
 
+/// Drops the fields of a value which are matched by a rest pattern, once the
+/// fields in `named` have been restructured.
+///
+/// The rest pattern is parsed like a field of the value which binds `()`, and
+/// must be restructured after the fields in `named`. If `leaves` is provided,
+/// it is instead pushed to `leaves` as a [`Leaf`] which evaluates to the
+/// pointer to the value.
+fn make_rest(
+    crate_path: &Path,
+    rest: &PatRest,
+    named: TokenStream,
+    leaves: Option<&mut Vec<Leaf>>,
+) -> (TokenStream, TokenStream) {
+    let span = rest.dot2_token.span();
+    if let Some(leaves) = leaves {
+        let ptr =
+            Ident::new(&format!("ptr_{}", leaves.len()), Span::mixed_site());
+        leaves.push(Leaf {
+            attrs: Vec::new(),
+            binding: TokenStream::new(),
+            declared: false,
+            ptr: ptr.clone(),
+            restructure: TokenStream::new(),
+            rest: Some((span, named)),
+        });
+        return (ptr.into_token_stream(), quote! { ptr });
+    }
+
+    let drop_rest =
+        rest_call(crate_path, span, "drop_rest", quote! { ptr, #named });
+    (
+        quote! { () },
+        quote! {
+            // SAFETY: `ptr` is a properly-aligned pointer to a subfield of the
+            // pointer underlying `destructurer`, and only the fields in
+            // `named` have been restructured.
+            unsafe { #drop_rest }
+        },
+    )
+}
+
+/// Returns a call to a method of `RestPattern` which drops the fields of a
+/// rest pattern.
+///
+/// The destructurer is cast to a `RestPattern` trait object at the rest
+/// pattern, so that errors point at the rest pattern.
+fn rest_call(
+    crate_path: &Path,
+    span: Span,
+    name: &str,
+    args: TokenStream,
+) -> TokenStream {
+    let destructurer = quote! { &destructurer };
+    let name = Ident::new(name, span);
+    quote_spanned! { span => {
+        let rest = #destructurer;
+        let rest = rest as &dyn #crate_path::__macro::RestPattern<_>;
+        rest.#name(#args)
+    } }
+}
+
+/// Returns the fields named by a struct pattern with a rest pattern.
+fn named_fields(crate_path: &Path, pat_struct: &PatStruct) -> TokenStream {
+    let names = pat_struct.fields.iter().map(|fp| {
+        let attrs = carried_attrs(&fp.attrs);
+        let name = match &fp.member {
+            Member::Named(ident) => ident.unraw().to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        };
+        quote! { #(#attrs)* #name }
+    });
+    quote! { #crate_path::__macro::Named::Fields(&[#(#names),*]) }
+}
+
+/// Returns the elements named by a tuple, tuple struct, or slice pattern with
+/// `front` elements before its rest pattern and `back` elements after it.
+fn named_elements(crate_path: &Path, front: usize, back: usize) -> TokenStream {
+    quote! { #crate_path::__macro::Named::Elements(#front, #back) }
+}
+
+/// Returns the rest pattern of a tuple, tuple struct, or slice pattern and its
+/// position.
+fn find_rest(elems: &Punctuated<Pat, Token![,]>) -> Option<(usize, &PatRest)> {
+    elems.iter().enumerate().find_map(|(i, e)| match e {
+        Pat::Rest(rest) => Some((i, rest)),
+        _ => None,
+    })
+}
+
+/// Rejects rest patterns which aren't the last element of a tuple or tuple
+/// struct pattern.
+///
+/// Tuple fields are projected by index, and the number of fields after the
+/// rest pattern isn't known when the pattern is expanded.
+fn check_tuple_rest(elems: &Punctuated<Pat, Token![,]>) -> Result<(), Error> {
+    match find_rest(elems) {
+        Some((i, rest)) if i + 1 != elems.len() => Err(Error::new_spanned(
+            rest,
+            "rest patterns must be last in irrefutable tuple and tuple struct \
+             patterns; replace `..` with a `_` for each field it skips",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// A binding of a refutable pattern.
+struct Leaf {
+    /// The attributes of the field patterns which contain the binding.
+    attrs: Vec<Attribute>,
+    /// The binding introduced by the pattern.
+    binding: TokenStream,
+    /// Whether the binding is declared before any leaves are restructured, as
+    /// the hidden bindings for wildcards are.
+    declared: bool,
+    /// The name of the pointer to the bound field.
+    ptr: Ident,
+    /// The function which restructures the pointer to the bound field.
+    restructure: TokenStream,
+    /// The span of the pattern and the fields it names, if this is a rest
+    /// pattern. The fields it doesn't name are dropped instead of bound.
+    rest: Option<(Span, TokenStream)>,
+}
+
+/// Parses a destructuring pattern, returning the bindings it introduces and an
+/// expression which evaluates to the restructured values for those bindings.
+///
+/// If `leaves` is provided, the pattern is parsed as a refutable pattern. The
+/// bindings are instead pointers to each field that is bound, and each binding
+/// is pushed to `leaves` as a [`Leaf`]. Struct, tuple struct, and path patterns
+/// are matched against a reference to the underlying value, and break out of
+/// `'munge` with `None` if they don't match.
+///
+/// Wildcards and path patterns don't bind their fields, but their fields are
+/// still restructured. Like the unbound fields of a value which is partially
+/// moved, they are kept alive until the end of the scope. The hidden bindings
+/// which keep them alive are pushed to `wildcards`, and must be declared with
+/// [`declare_wildcards`] before the bindings of the pattern.
 fn parse_pat(
     crate_path: &Path,
     pat: &Pat,
+    mut leaves: Option<&mut Vec<Leaf>>,
+    wildcards: &mut Vec<Wildcard>,
 ) -> Result<(TokenStream, TokenStream), Error> {
     let test_ident = quote_spanned!(pat.span() => test);
     let test_ident_ref = quote_spanned!(pat.span() => &test);
@@ -92,63 +520,101 @@ fn parse_pat(
         let _: &dyn #crate_path::__macro::MustBeAValue = #test_ident_ref;
     };
 
+    if let Some(leaves) = leaves.as_deref_mut() {
+        if let Some(parsed) =
+            parse_refutable_node(crate_path, pat, &test, leaves, wildcards)?
+        {
+            return Ok(parsed);
+        }
+    }
+
     Ok(match pat {
+        Pat::Path(_) => {
+            make_wildcard(crate_path, quote! { _ }, &test, wildcards)
+        }
         Pat::Ident(pat_ident) => {
-            let mutability = &pat_ident.mutability;
-            let ident = &pat_ident.ident;
-
-            if let Some(r#ref) = &pat_ident.by_ref {
-                return Err(Error::new_spanned(
-                    r#ref,
-                    "`ref` is not allowed in munge destructures",
-                ));
-            }
-            if let Some((at, _)) = &pat_ident.subpat {
-                return Err(Error::new_spanned(
-                    at,
-                    "subpatterns are not allowed in munge destructures",
-                ));
-            }
-
-            (
-                quote! { #mutability #ident },
-                quote! {
-                    #test
-
-                    // SAFETY: `ptr` is a properly-aligned pointer to a subfield
-                    // of the pointer underlying `destructurer`.
-                    unsafe {
-                        #crate_path::__macro::restructure_destructurer(
-                            &destructurer,
-                            ptr,
+            let mode = check_binding(pat_ident)?;
+            let binding = make_binding(crate_path, pat_ident, mode, &test);
+            bind_subpat(crate_path, pat_ident, binding, leaves, wildcards)?
+        }
+        Pat::Macro(pat_macro) if is_raw_binding(pat_macro) => {
+            let pat_ident = parse_raw_binding(pat_macro)?;
+            make_binding(crate_path, &pat_ident, Mode::Raw, &test)
+        }
+        Pat::Macro(pat_macro) if is_placeholder(pat_macro) => {
+            // Placeholders don't test the field, since that could report more
+            // errors for a pattern which already has one.
+            let no_test = TokenStream::new();
+            let (bindings, exprs) = parse_placeholder(pat_macro)?
+                .iter()
+                .map(|pat_ident| match leaves.as_deref_mut() {
+                    Some(leaves) => {
+                        let mutability = &pat_ident.mutability;
+                        let ident = &pat_ident.ident;
+                        let binding = quote! { #mutability #ident };
+                        make_leaf(
+                            crate_path,
+                            binding,
+                            false,
+                            Mode::Value,
+                            &no_test,
+                            leaves,
                         )
                     }
-                },
+                    None => make_binding(
+                        crate_path,
+                        pat_ident,
+                        Mode::Value,
+                        &no_test,
+                    ),
+                })
+                .unzip::<_, _, Vec<_>, Vec<_>>();
+            (quote! { (#(#bindings,)*) }, quote! { (#(#exprs,)*) })
+        }
+        Pat::Paren(pat_paren) => {
+            parse_pat(crate_path, &pat_paren.pat, leaves, wildcards)?
+        }
+        Pat::Type(pat_type) => {
+            let (bindings, expr) =
+                parse_pat(crate_path, &pat_type.pat, leaves, wildcards)?;
+            let ty = &pat_type.ty;
+            let ptr_ty = quote_spanned! { ty.span() => *mut #ty };
+            (
+                bindings,
+                quote! { {
+                    let ptr: #ptr_ty = ptr;
+
+                    #expr
+                } },
             )
         }
         Pat::Tuple(PatTuple { elems, .. })
         | Pat::TupleStruct(PatTupleStruct { elems, .. }) => {
-            let rest_check = elems.iter().find_map(|e| {
-                if let Pat::Rest(rest) = e {
-                    Some(make_rest_check(crate_path, rest))
-                } else {
-                    None
-                }
-            });
+            check_tuple_rest(elems)?;
             let parsed = elems
                 .iter()
                 .filter(|e| !matches!(e, Pat::Rest(_)))
-                .map(|e| parse_pat(crate_path, e))
+                .map(|e| {
+                    parse_pat(crate_path, e, leaves.as_deref_mut(), wildcards)
+                })
                 .collect::<Result<Vec<_>, Error>>()?;
             let (bindings, (exprs, indices)) = parsed
                 .iter()
                 .enumerate()
                 .map(|(i, x)| (&x.0, (&x.1, Index::from(i))))
                 .unzip::<_, _, Vec<_>, (Vec<_>, Vec<_>)>();
+            // The rest pattern is last, so it skips the elements after the
+            // ones that are projected.
+            let rest = find_rest(elems).map(|(_, rest)| {
+                let named = named_elements(crate_path, parsed.len(), 0);
+                make_rest(crate_path, rest, named, leaves)
+            });
+            let (rest_binding, rest_expr) = rest.unzip();
+            let (rest_binding, rest_expr) =
+                (rest_binding.iter(), rest_expr.iter());
             (
-                quote! { (#(#bindings,)*) },
+                quote! { (#(#bindings,)* #(#rest_binding,)*) },
                 quote! { {
-                    #rest_check
                     #test
 
                     ( #({
@@ -159,44 +625,74 @@ fn parse_pat(
                         };
 
                         #exprs
-                    },)* )
+                    },)* #(#rest_expr,)* )
                 } },
             )
         }
         Pat::Slice(pat_slice) => {
-            let rest_check = pat_slice.elems.iter().find_map(|e| {
-                if let Pat::Rest(rest) = e {
-                    Some(make_rest_check(crate_path, rest))
-                } else {
-                    None
-                }
-            });
+            let rest = find_rest(&pat_slice.elems);
+            let rest_position = rest.map(|(i, _)| i);
             let parsed = pat_slice
                 .elems
                 .iter()
                 .filter(|e| !matches!(e, Pat::Rest(_)))
-                .map(|e| parse_pat(crate_path, e))
+                .map(|e| {
+                    parse_pat(crate_path, e, leaves.as_deref_mut(), wildcards)
+                })
                 .collect::<Result<Vec<_>, Error>>()?;
-            let (bindings, (exprs, indices)) = parsed
+
+            // Elements after the rest pattern are indexed from the end of the
+            // array, which may have a length that depends on a const generic.
+            let len = parsed.len();
+            let front = rest_position.unwrap_or(len);
+            let exact = rest_position.is_none();
+            let indices = (0..len).map(|i| {
+                if i < front {
+                    quote! { #i }
+                } else {
+                    let from_end = len - i;
+                    quote! { len - #from_end }
+                }
+            });
+            let (bindings, exprs) = parsed
                 .iter()
-                .enumerate()
-                .map(|(i, x)| (&x.0, (&x.1, Index::from(i))))
-                .unzip::<_, _, Vec<_>, (Vec<_>, Vec<_>)>();
+                .map(|x| (&x.0, &x.1))
+                .unzip::<_, _, Vec<_>, Vec<_>>();
+            let rest = rest.map(|(_, rest)| {
+                let named = named_elements(crate_path, front, len - front);
+                make_rest(crate_path, rest, named, leaves)
+            });
+            let (rest_binding, rest_expr) = rest.unzip();
+            let (rest_binding, rest_expr) =
+                (rest_binding.iter(), rest_expr.iter());
+
+            let span = pat_slice.bracket_token.span.join();
+            let len_check = quote_spanned! { span =>
+                #crate_path::__macro::CheckLength::check
+            };
+            let array_len = quote! {
+                #crate_path::__macro::ArrayLen::<#len, #exact>
+            };
+
             (
-                quote! { (#(#bindings,)*) },
+                quote! { (#(#bindings,)* #(#rest_binding,)*) },
                 quote! { {
-                    #rest_check
                     #test
 
+                    #[allow(unused_variables)]
+                    let len = #len_check(#array_len, ptr);
+
                     ( #({
                         // SAFETY: `ptr` is guaranteed to always be non-null,
-                        // properly-aligned, and valid for reads.
+                        // properly-aligned, and valid for reads. The length of
+                        // the array or slice has been checked to contain the
+                        // index.
                         let ptr = unsafe {
                             ::core::ptr::addr_of_mut!((*ptr)[#indices])
                         };
 
                         #exprs
-                    },)* )
+                    },)* #(#rest_expr,)* )
                 } },
             )
         }
@@ -205,186 +701,2843 @@ fn parse_pat(
                 .fields
                 .iter()
                 .map(|fp| {
-                    parse_pat(crate_path, &fp.pat).map(|ie| (&fp.member, ie))
+                    let start = wildcards.len();
+                    let (binding, expr) = parse_pat(
+                        crate_path,
+                        &fp.pat,
+                        leaves.as_deref_mut(),
+                        wildcards,
+                    )?;
+                    carry_wildcard_attrs(&fp.attrs, wildcards, start);
+                    let member = &fp.member;
+                    let expr = quote! { {
+                        // SAFETY: `ptr` is guaranteed to always be non-null,
+                        // properly-aligned, and valid for reads.
+                        let ptr = unsafe {
+                            ::core::ptr::addr_of_mut!((*ptr).#member)
+                        };
+
+                        #expr
+                    } };
+                    Ok(carry_attrs(crate_path, &fp.attrs, (binding, expr)))
                 })
                 .collect::<Result<Vec<_>, Error>>()?;
-            let (members, (bindings, exprs)) =
-                parsed.into_iter().unzip::<_, _, Vec<_>, (Vec<_>, Vec<_>)>();
+            let (bindings, exprs) =
+                parsed.into_iter().unzip::<_, _, Vec<_>, Vec<_>>();
 
-            let rest_check = pat_struct
-                .rest
-                .as_ref()
-                .map(|rest| make_rest_check(crate_path, rest));
+            let rest = pat_struct.rest.as_ref().map(|rest| {
+                let named = named_fields(crate_path, pat_struct);
+                make_rest(crate_path, rest, named, leaves)
+            });
+            let (rest_binding, rest_expr) = rest.unzip();
+            let (rest_binding, rest_expr) =
+                (rest_binding.iter(), rest_expr.iter());
+            let union_checks = pat_struct.fields.iter().filter_map(|fp| {
+                union_attr(&fp.attrs).map(|attr| {
+                    let attrs = carried_attrs(&fp.attrs);
+                    let check = quote_spanned! { attr.span() =>
+                        #crate_path::__macro::check_project_unions
+                    };
+                    quote! { #(#attrs)* #check(&destructurer); }
+                })
+            });
+
+            let union_check = make_union_check(pat_struct);
 
             (
                 quote! { (
                     #(#bindings,)*
+                    #(#rest_binding,)*
                 ) },
                 quote! { {
-                    #rest_check
+                    #(#union_checks)*
+                    #union_check
                     #test
 
-                    ( #({
-                        // SAFETY: `ptr` is guaranteed to always be non-null,
-                        // properly-aligned, and valid for reads.
-                        let ptr = unsafe {
-                            ::core::ptr::addr_of_mut!((*ptr).#members)
-                        };
-
-                        #exprs
-                    },)* )
+                    ( #(#exprs,)* #(#rest_expr,)* )
                 } },
             )
         }
-        Pat::Rest(_) => unreachable!(
-            "rest patterns only occur in tuples, tuple structs, and slices"
-        ),
         Pat::Wild(pat_wild) => {
             let token = &pat_wild.underscore_token;
-            (
-                quote! { #token },
-                quote! {
-                    #test
-
-                    // SAFETY: `ptr` is a properly-aligned pointer to a subfield
-                    // of the pointer underlying `destructurer`.
-                    unsafe {
-                        #crate_path::__macro::restructure_destructurer(
-                            &destructurer,
-                            ptr,
-                        )
-                    }
-                },
-            )
-        }
-        _ => {
-            return Err(Error::new_spanned(
-                pat,
-                "expected a destructuring pattern",
-            ));
+            make_wildcard(crate_path, quote! { #token }, &test, wildcards)
         }
+        _ => return Err(unsupported_pat(pat)),
     })
 }
 
-fn strip_mut(pat: &Pat) -> Result<Pat, Error> {
-    Ok(match pat {
-        Pat::Ident(pat_ident) => Pat::Ident(PatIdent {
-            attrs: pat_ident.attrs.clone(),
-            by_ref: None,
-            mutability: None,
-            ident: pat_ident.ident.clone(),
-            subpat: if let Some((at, pat)) = pat_ident.subpat.as_ref() {
-                Some((*at, Box::new(strip_mut(pat)?)))
-            } else {
-                None
-            },
-        }),
-        Pat::Tuple(pat_tuple) => {
-            let mut elems = Punctuated::new();
-            for elem in pat_tuple.elems.iter() {
-                elems.push_value(strip_mut(elem)?);
-                elems.push_punct(Default::default());
-            }
-            Pat::Tuple(PatTuple {
-                attrs: pat_tuple.attrs.clone(),
-                paren_token: pat_tuple.paren_token,
-                elems,
-            })
+/// Generates code which restructures and drops every field bound by `pat` that
+/// was not restructured before a panic.
+///
+/// Fields are visited in the same order that `parse_pat` restructures them, so
+/// the destructurer can tell which fields were already moved out of the
+/// destructured value. Destructurers which don't move their fields ignore
+/// this.
+fn drop_unrestructured(crate_path: &Path, pat: &Pat) -> TokenStream {
+    let leaf = quote! {
+        // SAFETY: `ptr` is a properly-aligned pointer to a subfield of the
+        // pointer underlying `destructurer`, visited in the same order that
+        // fields are restructured.
+        unsafe {
+            #crate_path::__macro::drop_unrestructured(
+                &destructurer,
+                ptr,
+                &mut index,
+            );
         }
-        Pat::TupleStruct(pat_tuple_struct) => {
-            let mut elems = Punctuated::new();
-            for elem in pat_tuple_struct.elems.iter() {
-                elems.push(strip_mut(elem)?);
-            }
-            Pat::TupleStruct(PatTupleStruct {
-                attrs: pat_tuple_struct.attrs.clone(),
-                qself: pat_tuple_struct.qself.clone(),
-                path: pat_tuple_struct.path.clone(),
-                paren_token: pat_tuple_struct.paren_token,
-                elems,
-            })
+    };
+    let project = |member: TokenStream, drop: TokenStream| {
+        quote! { {
+            // SAFETY: `ptr` is guaranteed to always be non-null,
+            // properly-aligned, and valid for reads.
+            let ptr = unsafe { ::core::ptr::addr_of_mut!((*ptr)#member) };
+
+            #drop
+        } }
+    };
+    let rest = |rest: &PatRest, named: TokenStream| {
+        let span = rest.dot2_token.span();
+        let drop_rest = rest_call(
+            crate_path,
+            span,
+            "drop_unrestructured_rest",
+            quote! { ptr, #named, &mut index },
+        );
+        quote! {
+            // SAFETY: `ptr` is a properly-aligned pointer to a subfield of the
+            // pointer underlying `destructurer`, visited in the same order
+            // that fields are restructured.
+            unsafe { #drop_rest; }
         }
-        Pat::Slice(pat_slice) => {
-            let mut elems = Punctuated::new();
-            for elem in pat_slice.elems.iter() {
-                elems.push(strip_mut(elem)?);
+    };
+
+    match pat {
+        Pat::Ident(pat_ident) => match &pat_ident.subpat {
+            Some((_, subpat)) if !is_path_ident(pat_ident) => {
+                let sub_drop = drop_unrestructured(crate_path, subpat);
+                quote! { #leaf #sub_drop }
             }
-            Pat::Slice(PatSlice {
-                attrs: pat_slice.attrs.clone(),
+            _ => leaf,
+        },
+        Pat::Macro(pat_macro) if is_placeholder(pat_macro) => {
+            let count = parse_placeholder(pat_macro).map_or(0, |b| b.len());
+            let leaves = (0..count).map(|_| &leaf);
+            quote! { #(#leaves)* }
+        }
+        Pat::Paren(pat_paren) => {
+            drop_unrestructured(crate_path, &pat_paren.pat)
+        }
+        Pat::Type(pat_type) => drop_unrestructured(crate_path, &pat_type.pat),
+        Pat::Tuple(PatTuple { elems, .. })
+        | Pat::TupleStruct(PatTupleStruct { elems, .. }) => {
+            let drops = elems
+                .iter()
+                .filter(|e| !matches!(e, Pat::Rest(_)))
+                .enumerate()
+                .map(|(i, e)| {
+                    let index = Index::from(i);
+                    let drop = drop_unrestructured(crate_path, e);
+                    project(quote! { .#index }, drop)
+                })
+                .collect::<Vec<_>>();
+            let rest_drop = find_rest(elems).map(|(_, pat_rest)| {
+                rest(pat_rest, named_elements(crate_path, drops.len(), 0))
+            });
+            quote! { #(#drops)* #rest_drop }
+        }
+        Pat::Slice(pat_slice) => {
+            let pat_rest = find_rest(&pat_slice.elems);
+            let rest_position = pat_rest.map(|(i, _)| i);
+            let elems = pat_slice
+                .elems
+                .iter()
+                .filter(|e| !matches!(e, Pat::Rest(_)))
+                .collect::<Vec<_>>();
+            let len = elems.len();
+            let front = rest_position.unwrap_or(len);
+            let rest_drop = pat_rest.map(|(_, pat_rest)| {
+                rest(pat_rest, named_elements(crate_path, front, len - front))
+            });
+            let drops = elems.iter().enumerate().map(|(i, e)| {
+                let index = if i < front {
+                    quote! { #i }
+                } else {
+                    let from_end = len - i;
+                    quote! { len - #from_end }
+                };
+                project(quote! { [#index] }, drop_unrestructured(crate_path, e))
+            });
+            // The length was already checked when the fields were
+            // restructured, and checking it again could panic while unwinding.
+            quote! { {
+                #[allow(unused_variables)]
+                let len = #crate_path::__macro::slice_len(ptr);
+
+                #(#drops)*
+                #rest_drop
+            } }
+        }
+        Pat::Struct(pat_struct) => {
+            let drops = pat_struct.fields.iter().map(|fp| {
+                let member = &fp.member;
+                let drop = project(
+                    quote! { .#member },
+                    drop_unrestructured(crate_path, &fp.pat),
+                );
+                let attrs = carried_attrs(&fp.attrs);
+                quote! { #(#attrs)* #drop }
+            });
+            let rest_drop = pat_struct.rest.as_ref().map(|pat_rest| {
+                rest(pat_rest, named_fields(crate_path, pat_struct))
+            });
+            quote! { #(#drops)* #rest_drop }
+        }
+        // Every other pattern binds the whole field, or is an error which has
+        // already been reported.
+        _ => leaf,
+    }
+}
+
+/// Returns an error which explains why a pattern can't be used in a munge
+/// destructure and suggests an alternative.
+///
+/// Refutable patterns like literals are only rejected here when destructuring
+/// irrefutably, since refutable destructures match them in
+/// `parse_refutable_node`.
+fn unsupported_pat(pat: &Pat) -> Error {
+    let refutable = |kind: &str, what: String| {
+        Error::new_spanned(
+            pat,
+            format!(
+                "{kind} patterns are refutable and can't be used in \
+                 irrefutable munge destructures; use `_` instead of {what}, \
+                 or match it with `munge!(let ... else {{ ... }})`"
+            ),
+        )
+    };
+
+    match pat {
+        Pat::Lit(pat_lit) => refutable(
+            "literal",
+            format!("the literal `{}`", pat_lit.to_token_stream()),
+        ),
+        Pat::Range(pat_range) => {
+            let bound = |bound: &Option<Box<Expr>>| {
+                bound
+                    .as_ref()
+                    .map(|b| b.to_token_stream().to_string())
+                    .unwrap_or_default()
+            };
+            let limits = match pat_range.limits {
+                RangeLimits::HalfOpen(_) => "..",
+                RangeLimits::Closed(_) => "..=",
+            };
+            refutable(
+                "range",
+                format!(
+                    "the range `{}{limits}{}`",
+                    bound(&pat_range.start),
+                    bound(&pat_range.end),
+                ),
+            )
+        }
+        Pat::Const(_) => Error::new_spanned(
+            pat,
+            format!(
+                "inline const patterns can't be used in munge destructures; \
+                 use `_` or a binding instead of `{}`, and compare the field \
+                 after destructuring",
+                pat.to_token_stream(),
+            ),
+        ),
+        Pat::Or(_) => Error::new_spanned(
+            pat,
+            "or-patterns are refutable and can't be used in irrefutable munge \
+             destructures; match each alternative in a separate \
+             `munge_match!` arm instead",
+        ),
+        Pat::Reference(pat_reference) => {
+            let and_token = &pat_reference.and_token;
+            let mutability = &pat_reference.mutability;
+            let reference = match mutability {
+                Some(_) => "&mut",
+                None => "&",
+            };
+            Error::new_spanned(
+                quote! { #and_token #mutability },
+                format!(
+                    "reference patterns can't be used in munge destructures; \
+                     remove `{reference}`: munge bindings are already borrows",
+                ),
+            )
+        }
+        Pat::Macro(pat_macro) => Error::new_spanned(
+            pat,
+            format!(
+                "macro patterns can't be expanded in munge destructures; \
+                 write out the pattern that `{}!` expands to instead",
+                pat_macro.mac.path.to_token_stream(),
+            ),
+        ),
+        Pat::Rest(_) => Error::new_spanned(
+            pat,
+            "rest patterns can only be used in tuple, tuple struct, and slice \
+             patterns; use `_` to ignore a single field",
+        ),
+        _ => Error::new_spanned(
+            pat,
+            "this pattern can't be used in munge destructures; munge can only \
+             destructure tuples, structs, arrays, and slices into bindings",
+        ),
+    }
+}
+
+/// Returns the `#[union]` attribute which marks a field pattern as a union
+/// field, if any.
+fn union_attr(attrs: &[Attribute]) -> Option<&Attribute> {
+    attrs.iter().find(|a| a.path().is_ident("union"))
+}
+
+/// Returns the attributes of a field pattern which are carried into the code
+/// generated for that field. This is every attribute except `#[union]`.
+fn carried_attrs(attrs: &[Attribute]) -> Vec<&Attribute> {
+    attrs
+        .iter()
+        .filter(|a| !a.path().is_ident("union"))
+        .collect()
+}
+
+/// Applies the attributes of a field pattern to the bindings for that field
+/// and the block which evaluates to their restructured values.
+///
+/// The bindings and their values are wrapped in `Attributed` so that the
+/// attributes can be placed on its field in both the pattern and the
+/// expression. If the field is removed by `#[cfg]`, the pattern binds nothing
+/// and the field is never projected.
+fn carry_attrs(
+    crate_path: &Path,
+    attrs: &[Attribute],
+    (binding, expr): (TokenStream, TokenStream),
+) -> (TokenStream, TokenStream) {
+    let attrs = carried_attrs(attrs);
+    if attrs.is_empty() {
+        return (binding, expr);
+    }
+
+    let attributed = quote! { #crate_path::__macro::Attributed };
+    (
+        quote! { #attributed { #(#attrs)* value: #binding, .. } },
+        quote! { #attributed { value: { #(#attrs)* #expr } } },
+    )
+}
+
+/// Rejects struct patterns on unions unless their fields are marked with
+/// `#[union]`.
+///
+/// Union patterns may not contain `..`, so matching the struct with a rest
+/// pattern fails to compile for unions. Unlike accessing a union field, this
+/// doesn't depend on whether the destructure is inside of an `unsafe` block.
+fn make_union_check(pat_struct: &PatStruct) -> TokenStream {
+    if pat_struct
+        .fields
+        .iter()
+        .any(|f| union_attr(&f.attrs).is_some())
+    {
+        return TokenStream::new();
+    }
+
+    let fields = pat_struct.fields.iter().map(|f| {
+        let attrs = &f.attrs;
+        let member = &f.member;
+        quote! { #(#attrs)* #member: _ }
+    });
+    // The check is spanned like the pattern it checks, so that a mismatched
+    // type is reported once for both instead of once for each.
+    let mut pat = pat_struct.path.to_token_stream();
+    pat_struct.brace_token.surround(&mut pat, |tokens| {
+        tokens.extend(quote! { #(#fields,)* .. });
+    });
+    quote! {
+        #[allow(unreachable_patterns)]
+        if false {
+            // SAFETY: This code can never be called.
+            match unsafe { &*ptr } {
+                #pat => (),
+                _ => (),
+            }
+        }
+    }
+}
+
+/// How a binding restructures the field it binds.
+#[derive(Clone, Copy)]
+enum Mode {
+    /// Restructures the field with `Restructure`.
+    Value,
+    /// Restructures the field as a shared borrow with `RestructureRef`, for
+    /// `ref` bindings.
+    Ref,
+    /// Restructures the field as a raw pointer with `RestructureRaw`, for
+    /// `raw!` bindings.
+    Raw,
+}
+
+impl Mode {
+    /// Returns the function which restructures fields bound with this mode.
+    fn restructure(self, crate_path: &Path) -> TokenStream {
+        match self {
+            Self::Value => quote! {
+                #crate_path::__macro::restructure_destructurer
+            },
+            Self::Ref => quote! {
+                #crate_path::__macro::restructure_destructurer_ref
+            },
+            Self::Raw => quote! {
+                #crate_path::__macro::restructure_destructurer_raw
+            },
+        }
+    }
+}
+
+fn check_binding(pat_ident: &PatIdent) -> Result<Mode, Error> {
+    match (&pat_ident.by_ref, &pat_ident.mutability) {
+        (None, _) => Ok(Mode::Value),
+        (Some(_), None) => Ok(Mode::Ref),
+        (Some(r#ref), Some(mutability)) => Err(Error::new_spanned(
+            quote! { #r#ref #mutability },
+            "`ref mut` is not allowed in munge destructures",
+        )),
+    }
+}
+
+/// Returns whether a macro pattern is a `raw!` binding.
+fn is_raw_binding(pat_macro: &PatMacro) -> bool {
+    pat_macro.mac.path.is_ident("raw")
+}
+
+/// Parses a `raw!(x)` or `raw!(mut x)` binding into the binding it introduces.
+fn parse_raw_binding(pat_macro: &PatMacro) -> Result<PatIdent, Error> {
+    pat_macro.mac.parse_body_with(|input: parse::ParseStream| {
+        Ok(PatIdent {
+            attrs: Vec::new(),
+            by_ref: None,
+            mutability: input.parse()?,
+            ident: input.parse()?,
+            subpat: None,
+        })
+    })
+}
+
+/// Makes a binding which restructures the field pointed to by `ptr`.
+fn make_binding(
+    crate_path: &Path,
+    pat_ident: &PatIdent,
+    mode: Mode,
+    test: &TokenStream,
+) -> (TokenStream, TokenStream) {
+    let mutability = &pat_ident.mutability;
+    let ident = &pat_ident.ident;
+    let restructure = mode.restructure(crate_path);
+    (
+        quote! { #mutability #ident },
+        quote! { {
+            #test
+
+            // SAFETY: `ptr` is a properly-aligned pointer to a subfield of the
+            // pointer underlying `destructurer`.
+            unsafe { #restructure(&destructurer, ptr) }
+        } },
+    )
+}
+
+/// Makes a binding for a wildcard or a path pattern like a unit struct, which
+/// introduces no bindings. Path patterns are matched by the test pattern.
+///
+/// The field is restructured into a hidden binding which is pushed to
+/// `wildcards`, so that it is dropped at the end of the scope instead of right
+/// away.
+fn make_wildcard(
+    crate_path: &Path,
+    binding: TokenStream,
+    test: &TokenStream,
+    wildcards: &mut Vec<Wildcard>,
+) -> (TokenStream, TokenStream) {
+    let wildcard = wildcard(wildcards);
+    (
+        binding,
+        quote! { {
+            #test
+
+            // SAFETY: `ptr` is a properly-aligned pointer to a subfield of the
+            // pointer underlying `destructurer`.
+            #wildcard = unsafe {
+                #crate_path::__macro::restructure_destructurer(
+                    &destructurer,
+                    ptr,
+                )
+            };
+        } },
+    )
+}
+
+/// Returns a new hidden binding for a wildcard and pushes it to `wildcards`.
+fn wildcard(wildcards: &mut Vec<Wildcard>) -> Ident {
+    let ident = Ident::new(
+        &format!("_wildcard_{}", wildcards.len()),
+        Span::mixed_site(),
+    );
+    wildcards.push(Wildcard {
+        attrs: Vec::new(),
+        ident: ident.clone(),
+    });
+    ident
+}
+
+/// Applies the attributes of a field pattern to the hidden bindings for the
+/// wildcards in that field, starting at `start`.
+///
+/// If the field is removed by `#[cfg]`, its wildcards are never assigned, and
+/// so they must not be declared either.
+fn carry_wildcard_attrs(
+    attrs: &[Attribute],
+    wildcards: &mut [Wildcard],
+    start: usize,
+) {
+    let attrs = carried_attrs(attrs);
+    for wildcard in &mut wildcards[start..] {
+        wildcard.attrs.splice(0..0, attrs.iter().copied().cloned());
+    }
+}
+
+/// The hidden binding for a wildcard or a path pattern.
+struct Wildcard {
+    /// The attributes of the field patterns which contain the wildcard.
+    attrs: Vec<Attribute>,
+    /// The name of the hidden binding.
+    ident: Ident,
+}
+
+/// Declares the hidden bindings for wildcards.
+///
+/// The bindings are declared before the bindings of the pattern, so they are
+/// dropped after them like the unbound fields of a partially moved value. They
+/// are declared in reverse so that they are dropped in the order of their
+/// fields.
+fn declare_wildcards(wildcards: &[Wildcard]) -> TokenStream {
+    let wildcards = wildcards.iter().rev().map(|Wildcard { attrs, ident }| {
+        quote! { #(#attrs)* let #ident; }
+    });
+    quote! { #(#wildcards)* }
+}
+
+/// Adds the bindings of the subpattern of `pat_ident` to the binding for the
+/// whole field, if it has a subpattern.
+///
+/// Binding a field and its subfields creates aliasing restructured values, so
+/// the destructured type must be `Aliasable`.
+fn bind_subpat(
+    crate_path: &Path,
+    pat_ident: &PatIdent,
+    (binding, expr): (TokenStream, TokenStream),
+    leaves: Option<&mut Vec<Leaf>>,
+    wildcards: &mut Vec<Wildcard>,
+) -> Result<(TokenStream, TokenStream), Error> {
+    let Some((at, subpat)) = &pat_ident.subpat else {
+        return Ok((binding, expr));
+    };
+
+    let (sub_binding, sub_expr) =
+        parse_pat(crate_path, subpat, leaves, wildcards)?;
+    let check = quote_spanned! { at.span() =>
+        #crate_path::__macro::check_aliasable
+    };
+    Ok((
+        quote! { (#binding, #sub_binding) },
+        quote! { {
+            #check(&destructurer);
+
+            (#expr, #sub_expr)
+        } },
+    ))
+}
+
+/// Parses the parts of a refutable pattern which differ from irrefutable
+/// patterns, or returns `None` if the pattern is parsed the same way.
+fn parse_refutable_node(
+    crate_path: &Path,
+    pat: &Pat,
+    test: &TokenStream,
+    leaves: &mut Vec<Leaf>,
+    wildcards: &mut Vec<Wildcard>,
+) -> Result<Option<(TokenStream, TokenStream)>, Error> {
+    let field =
+        |i: usize| Ident::new(&format!("field_{i}"), Span::mixed_site());
+    let bind = |ident: Ident| {
+        Box::new(Pat::Ident(PatIdent {
+            attrs: Vec::new(),
+            by_ref: None,
+            mutability: None,
+            ident,
+            subpat: None,
+        }))
+    };
+
+    let mut union_check = TokenStream::new();
+    let mut attrs = Vec::new();
+    let (match_pat, subpats, rest) = match pat {
+        Pat::Ident(pat_ident) if is_path_ident(pat_ident) => {
+            (pat.clone(), Vec::new(), None)
+        }
+        Pat::Ident(pat_ident) => {
+            let mode = check_binding(pat_ident)?;
+            let mutability = &pat_ident.mutability;
+            let ident = &pat_ident.ident;
+            let binding = quote! { #mutability #ident };
+            let leaf =
+                make_leaf(crate_path, binding, false, mode, test, leaves);
+            return bind_subpat(
+                crate_path,
+                pat_ident,
+                leaf,
+                Some(leaves),
+                wildcards,
+            )
+            .map(Some);
+        }
+        Pat::Macro(pat_macro) if is_raw_binding(pat_macro) => {
+            let PatIdent {
+                mutability, ident, ..
+            } = parse_raw_binding(pat_macro)?;
+            let binding = quote! { #mutability #ident };
+            let leaf =
+                make_leaf(crate_path, binding, false, Mode::Raw, test, leaves);
+            return Ok(Some(leaf));
+        }
+        Pat::Wild(_) => {
+            let binding = wildcard(wildcards).into_token_stream();
+            let leaf =
+                make_leaf(crate_path, binding, true, Mode::Value, test, leaves);
+            return Ok(Some(leaf));
+        }
+        Pat::TupleStruct(pat_tuple_struct) => {
+            let mut match_pat = pat_tuple_struct.clone();
+            let mut subpats = Vec::new();
+            for elem in match_pat.elems.iter_mut() {
+                if !matches!(elem, Pat::Rest(_)) {
+                    let field = bind(field(subpats.len()));
+                    subpats.push(core::mem::replace(elem, *field));
+                }
+            }
+            // Unlike irrefutable patterns, the elements after the rest pattern
+            // are bound by matching and so are the last elements of the tuple
+            // struct.
+            let rest = find_rest(&pat_tuple_struct.elems).map(|(i, rest)| {
+                let named = named_elements(crate_path, i, subpats.len() - i);
+                (rest.clone(), named)
+            });
+            (Pat::TupleStruct(match_pat), subpats, rest)
+        }
+        Pat::Struct(pat_struct) => {
+            check_refutable_struct(pat_struct)?;
+            let mut match_pat = pat_struct.clone();
+            let mut subpats = Vec::new();
+            for (i, field_pat) in match_pat.fields.iter_mut().enumerate() {
+                field_pat.colon_token = Some(Default::default());
+                subpats.push(*core::mem::replace(
+                    &mut field_pat.pat,
+                    bind(field(i)),
+                ));
+                attrs.push(field_pat.attrs.clone());
+            }
+            let rest = pat_struct
+                .rest
+                .clone()
+                .map(|rest| (rest, named_fields(crate_path, pat_struct)));
+            union_check = make_union_check(pat_struct);
+            (Pat::Struct(match_pat), subpats, rest)
+        }
+        Pat::Path(_) | Pat::Lit(_) | Pat::Range(_) => {
+            (pat.clone(), Vec::new(), None)
+        }
+        Pat::Or(pat_or) => {
+            check_refutable_or(pat)?;
+            (Pat::Or(pat_or.clone()), Vec::new(), None)
+        }
+        _ => return Ok(None),
+    };
+
+    attrs.resize(subpats.len(), Vec::new());
+    let (ptr_pats, exprs) = subpats
+        .iter()
+        .zip(attrs)
+        .enumerate()
+        .map(|(i, (subpat, attrs))| {
+            let start = leaves.len();
+            let wildcards_start = wildcards.len();
+            let (ptr_pat, expr) =
+                parse_pat(crate_path, subpat, Some(leaves), wildcards)?;
+            for leaf in &mut leaves[start..] {
+                leaf.attrs.splice(0..0, attrs.iter().cloned());
+            }
+            carry_wildcard_attrs(&attrs, wildcards, wildcards_start);
+            let field = field(i);
+            let expr = quote! { {
+                let ptr = #crate_path::__macro::field_ptr(#field);
+
+                #expr
+            } };
+            Ok(carry_attrs(crate_path, &attrs, (ptr_pat, expr)))
+        })
+        .collect::<Result<Vec<_>, Error>>()?
+        .into_iter()
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+    let rest = rest
+        .map(|(rest, named)| make_rest(crate_path, &rest, named, Some(leaves)));
+    let (rest_ptr_pat, rest_expr) = rest.unzip();
+    let (rest_ptr_pat, rest_expr) = (rest_ptr_pat.iter(), rest_expr.iter());
+
+    let ptr_pat = quote! { (#(#ptr_pats,)* #(#rest_ptr_pat,)*) };
+    let result = quote! { ( #(#exprs,)* #(#rest_expr,)* ) };
+
+    Ok(Some((
+        ptr_pat,
+        quote! { {
+            #union_check
+            #test
+
+            // SAFETY: `ptr` is guaranteed to always be non-null,
+            // properly-aligned, and valid for reads. The destructured value is
+            // `DiscriminantStable`, so its discriminant may be read.
+            let value = unsafe { #crate_path::__macro::access(access, ptr) };
+            match value {
+                #match_pat => #result,
+                _ => break 'munge ::core::option::Option::None,
+            }
+        } },
+    )))
+}
+
+/// Rejects struct patterns with union fields in refutable patterns.
+fn check_refutable_struct(pat_struct: &PatStruct) -> Result<(), Error> {
+    if let Some(attr) =
+        pat_struct.fields.iter().find_map(|f| union_attr(&f.attrs))
+    {
+        return Err(Error::new_spanned(
+            attr,
+            "union fields may not be destructured with refutable patterns",
+        ));
+    }
+    Ok(())
+}
+
+/// Rejects or-patterns which introduce bindings in refutable patterns.
+fn check_refutable_or(pat: &Pat) -> Result<(), Error> {
+    let mut bindings = Bindings(Vec::new());
+    bindings.visit_pat(pat);
+    if let Some(binding) = bindings.0.iter().find(|b| !is_path_ident(b)) {
+        return Err(Error::new_spanned(
+            binding,
+            "or-patterns may not introduce bindings in munge destructures",
+        ));
+    }
+    Ok(())
+}
+
+/// Returns whether an identifier pattern in a refutable pattern names a unit
+/// struct, unit variant, or constant instead of introducing a binding. Like
+/// `None`, these are expected to start with an uppercase letter.
+///
+/// Identifiers in irrefutable patterns always introduce bindings, like they do
+/// in a `let` statement which doesn't shadow any unit structs or constants.
+fn is_path_ident(pat_ident: &PatIdent) -> bool {
+    pat_ident.by_ref.is_none()
+        && pat_ident.mutability.is_none()
+        && pat_ident.subpat.is_none()
+        && pat_ident
+            .ident
+            .to_string()
+            .starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+/// Makes a binding of a refutable pattern, which evaluates to the pointer to
+/// the bound field.
+fn make_leaf(
+    crate_path: &Path,
+    binding: TokenStream,
+    declared: bool,
+    mode: Mode,
+    test: &TokenStream,
+    leaves: &mut Vec<Leaf>,
+) -> (TokenStream, TokenStream) {
+    let ptr = Ident::new(&format!("ptr_{}", leaves.len()), Span::mixed_site());
+    leaves.push(Leaf {
+        attrs: Vec::new(),
+        binding,
+        declared,
+        ptr: ptr.clone(),
+        restructure: mode.restructure(crate_path),
+        rest: None,
+    });
+    (
+        ptr.into_token_stream(),
+        quote! { {
+            #test
+
+            ptr
+        } },
+    )
+}
+
+/// The name of the macro pattern which replaces parts of a pattern that can't
+/// be destructured.
+const PLACEHOLDER: &str = "__munge_placeholder";
+
+/// Replaces the parts of a pattern which can't be destructured with
+/// placeholders, pushing an error for each of them to `errors`.
+///
+/// A placeholder binds each of the bindings in the part it replaces to the
+/// field that part would have destructured. The rest of the pattern is still
+/// destructured, so later uses of the bindings have types and don't report
+/// more errors.
+fn recover_pat(pat: &mut Pat, refutable: bool, errors: &mut Vec<Error>) {
+    // Refutable tuple struct patterns bind their fields by matching, so their
+    // rest patterns may be anywhere.
+    let tuple_rest = match pat {
+        Pat::Tuple(PatTuple { elems, .. }) => check_tuple_rest(elems).err(),
+        Pat::TupleStruct(PatTupleStruct { elems, .. }) if !refutable => {
+            check_tuple_rest(elems).err()
+        }
+        _ => None,
+    };
+    let error = match pat {
+        Pat::Ident(pat_ident) => {
+            if let Err(e) = check_binding(pat_ident) {
+                errors.push(e);
+                pat_ident.by_ref = None;
+            }
+            if let Some((_, subpat)) = &mut pat_ident.subpat {
+                recover_pat(subpat, refutable, errors);
+            }
+            None
+        }
+        Pat::Paren(PatParen { pat, .. }) | Pat::Type(PatType { pat, .. }) => {
+            recover_pat(pat, refutable, errors);
+            None
+        }
+        Pat::Tuple(PatTuple { elems, .. })
+        | Pat::TupleStruct(PatTupleStruct { elems, .. })
+        | Pat::Slice(PatSlice { elems, .. }) => {
+            let error = tuple_rest;
+            if error.is_none() {
+                for elem in elems.iter_mut() {
+                    if !matches!(elem, Pat::Rest(_)) {
+                        recover_pat(elem, refutable, errors);
+                    }
+                }
+            }
+            error
+        }
+        Pat::Struct(pat_struct) => {
+            let error = refutable
+                .then(|| check_refutable_struct(pat_struct).err())
+                .flatten();
+            if error.is_none() {
+                for field in pat_struct.fields.iter_mut() {
+                    recover_pat(&mut field.pat, refutable, errors);
+                }
+            }
+            error
+        }
+        Pat::Path(_) | Pat::Wild(_) => None,
+        Pat::Lit(_) | Pat::Range(_) if refutable => None,
+        Pat::Or(_) if refutable => check_refutable_or(pat).err(),
+        Pat::Macro(pat_macro) if is_raw_binding(pat_macro) => {
+            parse_raw_binding(pat_macro).err()
+        }
+        _ => Some(unsupported_pat(pat)),
+    };
+
+    if let Some(error) = error {
+        errors.push(error);
+        *pat = make_placeholder(pat, refutable);
+    }
+}
+
+/// Makes a placeholder for a pattern which can't be destructured, which binds
+/// each of the bindings in the pattern.
+fn make_placeholder(pat: &Pat, refutable: bool) -> Pat {
+    let mut bindings = Bindings(Vec::new());
+    bindings.visit_pat(pat);
+    let mut seen = Vec::new();
+    let bindings = bindings.0.iter().filter_map(|b| {
+        if (refutable && is_path_ident(b)) || seen.contains(&&b.ident) {
+            return None;
+        }
+        seen.push(&b.ident);
+        let mutability = &b.mutability;
+        let ident = &b.ident;
+        Some(quote! { #mutability #ident })
+    });
+    let placeholder = Ident::new(PLACEHOLDER, pat.span());
+    parse_quote! { #placeholder!(#(#bindings),*) }
+}
+
+/// Returns whether a macro pattern is a placeholder made by `recover_pat`.
+fn is_placeholder(pat_macro: &PatMacro) -> bool {
+    pat_macro.mac.path.is_ident(PLACEHOLDER)
+}
+
+/// Parses the bindings of a placeholder made by `recover_pat`.
+fn parse_placeholder(pat_macro: &PatMacro) -> Result<Vec<PatIdent>, Error> {
+    pat_macro.mac.parse_body_with(|input: parse::ParseStream| {
+        let mut bindings = Vec::new();
+        while !input.is_empty() {
+            bindings.push(PatIdent {
+                attrs: Vec::new(),
+                by_ref: None,
+                mutability: input.parse()?,
+                ident: input.parse()?,
+                subpat: None,
+            });
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(bindings)
+    })
+}
+
+/// Strips `mut` and `ref` from a pattern so it can be used to test the
+/// destructuring.
+///
+/// Slice patterns with rest patterns can't match arrays with a length that
+/// depends on a const generic, so they are replaced with bindings. Each binding
+/// is tested against the original slice pattern in a separate `match` pushed to
+/// `checks`.
+fn strip_mut(
+    crate_path: &Path,
+    pat: &Pat,
+    checks: &mut Vec<TokenStream>,
+) -> Result<Pat, Error> {
+    Ok(match pat {
+        Pat::Ident(pat_ident) => Pat::Ident(PatIdent {
+            attrs: pat_ident.attrs.clone(),
+            by_ref: None,
+            mutability: None,
+            ident: pat_ident.ident.clone(),
+            subpat: if let Some((at, pat)) = pat_ident.subpat.as_ref() {
+                Some((*at, Box::new(strip_mut(crate_path, pat, checks)?)))
+            } else {
+                None
+            },
+        }),
+        Pat::Tuple(pat_tuple) => {
+            let mut elems = Punctuated::new();
+            for elem in pat_tuple.elems.iter() {
+                elems.push_value(strip_mut(crate_path, elem, checks)?);
+                elems.push_punct(Default::default());
+            }
+            Pat::Tuple(PatTuple {
+                attrs: pat_tuple.attrs.clone(),
+                paren_token: pat_tuple.paren_token,
+                elems,
+            })
+        }
+        Pat::TupleStruct(pat_tuple_struct) => {
+            let mut elems = Punctuated::new();
+            for elem in pat_tuple_struct.elems.iter() {
+                elems.push(strip_mut(crate_path, elem, checks)?);
+            }
+            Pat::TupleStruct(PatTupleStruct {
+                attrs: pat_tuple_struct.attrs.clone(),
+                qself: pat_tuple_struct.qself.clone(),
+                path: pat_tuple_struct.path.clone(),
+                paren_token: pat_tuple_struct.paren_token,
+                elems,
+            })
+        }
+        Pat::Slice(pat_slice) => {
+            let has_rest =
+                pat_slice.elems.iter().any(|e| matches!(e, Pat::Rest(_)));
+
+            let mut inner_checks = Vec::new();
+            let elem_checks = if has_rest {
+                &mut inner_checks
+            } else {
+                &mut *checks
+            };
+            let mut elems = Punctuated::new();
+            for elem in pat_slice.elems.iter() {
+                elems.push(strip_mut(crate_path, elem, elem_checks)?);
+            }
+            let slice = PatSlice {
+                attrs: pat_slice.attrs.clone(),
                 bracket_token: pat_slice.bracket_token,
                 elems,
+            };
+            if !has_rest {
+                return Ok(Pat::Slice(slice));
+            }
+
+            let span = pat_slice.bracket_token.span.join();
+            let ident =
+                format_ident!("__munge_array_{}", checks.len(), span = span);
+            checks.push(quote_spanned! { span =>
+                match #crate_path::__macro::test_array(&#ident) {
+                    #slice => { #(#inner_checks)* }
+                    _ => (),
+                }
+            });
+
+            Pat::Ident(PatIdent {
+                attrs: Vec::new(),
+                by_ref: None,
+                mutability: None,
+                ident,
+                subpat: None,
             })
         }
         Pat::Struct(pat_struct) => {
+            // Union fields can't be matched in safe code, so they're matched
+            // with wildcards instead. Each union field is tested against its
+            // subpattern separately, so only the marked field is accessed
+            // unsafely.
+            let has_union = pat_struct
+                .fields
+                .iter()
+                .any(|f| union_attr(&f.attrs).is_some());
+            let ident = format_ident!(
+                "__munge_union_{}",
+                checks.len(),
+                span = pat_struct.path.span(),
+            );
+            let mut union_checks = Vec::new();
+
             let mut fields = Punctuated::new();
             for field in pat_struct.fields.iter() {
+                let attrs = field
+                    .attrs
+                    .iter()
+                    .filter(|a| !a.path().is_ident("union"))
+                    .cloned()
+                    .collect();
+                let mut colon_token = field.colon_token;
+                let pat = if union_attr(&field.attrs).is_some() {
+                    let mut inner_checks = Vec::new();
+                    let pat =
+                        strip_mut(crate_path, &field.pat, &mut inner_checks)?;
+                    let member = &field.member;
+                    union_checks.push(quote! { {
+                        // SAFETY: This can never be called.
+                        let #pat = unsafe { &#ident.#member };
+                        #(#inner_checks)*
+                    } });
+                    colon_token = Some(Default::default());
+                    parse_quote! { _ }
+                } else {
+                    strip_mut(crate_path, &field.pat, checks)?
+                };
                 fields.push(FieldPat {
-                    attrs: field.attrs.clone(),
+                    attrs,
                     member: field.member.clone(),
-                    colon_token: field.colon_token,
-                    pat: Box::new(strip_mut(&field.pat)?),
+                    colon_token,
+                    pat: Box::new(pat),
                 });
             }
-            Pat::Struct(PatStruct {
+            let stripped = Pat::Struct(PatStruct {
                 attrs: pat_struct.attrs.clone(),
                 qself: pat_struct.qself.clone(),
                 path: pat_struct.path.clone(),
                 brace_token: pat_struct.brace_token,
                 fields,
                 rest: pat_struct.rest.clone(),
+            });
+            if !has_union {
+                return Ok(stripped);
+            }
+
+            checks.extend(union_checks);
+            Pat::Ident(PatIdent {
+                attrs: Vec::new(),
+                by_ref: None,
+                mutability: None,
+                ident,
+                subpat: Some((Default::default(), Box::new(stripped))),
             })
         }
         Pat::Rest(pat_rest) => Pat::Rest(pat_rest.clone()),
         Pat::Wild(pat_wild) => Pat::Wild(pat_wild.clone()),
-        _ => todo!(),
+        Pat::Path(_) | Pat::Lit(_) | Pat::Range(_) | Pat::Or(_) => pat.clone(),
+        Pat::Macro(pat_macro) if is_raw_binding(pat_macro) => {
+            Pat::Ident(PatIdent {
+                mutability: None,
+                ..parse_raw_binding(pat_macro)?
+            })
+        }
+        Pat::Macro(pat_macro) if is_placeholder(pat_macro) => {
+            parse_quote! { _ }
+        }
+        Pat::Paren(pat_paren) => Pat::Paren(PatParen {
+            attrs: pat_paren.attrs.clone(),
+            paren_token: pat_paren.paren_token,
+            pat: Box::new(strip_mut(crate_path, &pat_paren.pat, checks)?),
+        }),
+        // The type is checked against the pointer to the field instead.
+        Pat::Type(pat_type) => strip_mut(crate_path, &pat_type.pat, checks)?,
+        // Other patterns are rejected by `parse_pat`, which explains why they
+        // can't be used.
+        _ => pat.clone(),
     })
 }
 
 fn destructure(input: Input) -> Result<TokenStream, Error> {
     let crate_path = &input.crate_path;
 
-    let mut result = TokenStream::new();
-    for destructure in input.destructures.iter() {
-        let pat = &destructure.pat;
+    // Patterns are recovered from errors so that the bindings they could parse
+    // are still introduced. The errors are reported after the destructures.
+    let mut errors = Vec::new();
+    let destructures = match &input.body {
+        Body::Destructures(destructures) => destructures,
+        Body::Expr(expr, lift) => {
+            let mut pat = lift.pat.clone();
+            recover_pat(&mut pat, false, &mut errors);
+            let lifted = destructure_lift(crate_path, &pat, expr)?;
+            let errors = errors.iter().map(Error::to_compile_error);
+            return Ok(quote! { { #(#errors)* #lifted } });
+        }
+        Body::Match(expr, arms) => {
+            let mut arms = arms.clone();
+            for arm in arms.iter_mut() {
+                recover_pat(&mut arm.pat, true, &mut errors);
+            }
+            let matched = destructure_match(crate_path, expr, &arms)?;
+            let errors = errors.iter().map(Error::to_compile_error);
+            return Ok(quote! { { #(#errors)* #matched } });
+        }
+    };
+
+    let mut result = TokenStream::new();
+    for destructure in destructures.iter() {
         let expr = &destructure.expr;
+        let mut pat = destructure.pat.clone();
+        if destructure.lift.is_none() {
+            let refutable = destructure.else_block.is_some();
+            recover_pat(&mut pat, refutable, &mut errors);
+        }
+        let pat = &pat;
 
-        let test_pat = strip_mut(pat)?;
+        let destructured = if destructure.emplace.is_some() {
+            destructure_emplace(crate_path, pat, expr)
+        } else if let Some((_, else_block)) = &destructure.else_block {
+            destructure_let_else(crate_path, pat, expr, else_block)
+        } else {
+            match (&destructure.columns, &destructure.lift) {
+                (None, None) => destructure_expr(crate_path, pat, expr),
+                (Some(columns), _) => {
+                    destructure_columns(crate_path, pat, &columns.len, expr)
+                }
+                (None, Some(lift)) => {
+                    let mut lift_pat = lift.pat.clone();
+                    recover_pat(&mut lift_pat, false, &mut errors);
+                    destructure_lift(crate_path, &lift_pat, expr)
+                        .map(|lifted| quote! { let #pat = #lifted; })
+                }
+            }
+        };
+        match destructured.and_then(|d| carry_stmt_attrs(&destructure.attrs, d))
+        {
+            Ok(destructured) => result.extend(destructured),
+            Err(error) => errors.push(error),
+        }
+    }
+    result.extend(errors.iter().map(Error::to_compile_error));
+    Ok(result)
+}
 
-        let (bindings, exprs) = parse_pat(crate_path, pat)?;
+/// Applies the attributes of a `let` destructure to each of the statements it
+/// expands to.
+fn carry_stmt_attrs(
+    attrs: &[Attribute],
+    destructured: TokenStream,
+) -> Result<TokenStream, Error> {
+    if attrs.is_empty() {
+        return Ok(destructured);
+    }
+
+    let stmts = Block::parse_within.parse2(destructured)?;
+    Ok(stmts
+        .iter()
+        .map(|stmt| quote! { #(#attrs)* #stmt })
+        .collect())
+}
+
+/// Destructures an expression, splitting tuple expressions destructured with
+/// tuple patterns into independent destructures.
+fn destructure_expr(
+    crate_path: &Path,
+    pat: &Pat,
+    expr: &Expr,
+) -> Result<TokenStream, Error> {
+    if let Pat::Paren(pat_paren) = pat {
+        return destructure_expr(crate_path, &pat_paren.pat, expr);
+    }
+    if let Expr::Paren(expr_paren) = expr {
+        return destructure_expr(crate_path, pat, &expr_paren.expr);
+    }
+    if !matches!((pat, expr), (Pat::Tuple(_), Expr::Tuple(_))) {
+        return destructure_value(crate_path, pat, expr.to_token_stream());
+    }
+
+    let mut leaves = Vec::new();
+    split_tuples(pat, expr, &mut leaves)?;
+
+    // Evaluate all of the values before destructuring any of them, so the
+    // values can't refer to bindings from the destructured patterns. Bindings
+    // and wildcards don't need to be destructured, so they're bound directly.
+    let mut pats = Vec::new();
+    let mut exprs = Vec::new();
+    let mut destructures = TokenStream::new();
+    for (i, (pat, expr)) in leaves.into_iter().enumerate() {
+        exprs.push(expr);
+        if matches!(pat, Pat::Ident(_) | Pat::Wild(_)) {
+            pats.push(pat.to_token_stream());
+        } else {
+            let value = Ident::new(&format!("value_{i}"), Span::mixed_site());
+            pats.push(value.to_token_stream());
+            destructures.extend(destructure_value(
+                crate_path,
+                pat,
+                value.into_token_stream(),
+            )?);
+        }
+    }
+
+    Ok(quote! {
+        let (#(#pats,)*) = (#(#exprs,)*);
+        #destructures
+    })
+}
+
+/// Splits tuple patterns and tuple expressions into pairs of patterns and the
+/// values they destructure.
+fn split_tuples<'a>(
+    pat: &'a Pat,
+    expr: &'a Expr,
+    leaves: &mut Vec<(&'a Pat, &'a Expr)>,
+) -> Result<(), Error> {
+    if let Pat::Paren(pat_paren) = pat {
+        return split_tuples(&pat_paren.pat, expr, leaves);
+    }
+    if let Expr::Paren(expr_paren) = expr {
+        return split_tuples(pat, &expr_paren.expr, leaves);
+    }
+
+    let (Pat::Tuple(pat_tuple), Expr::Tuple(expr_tuple)) = (pat, expr) else {
+        leaves.push((pat, expr));
+        return Ok(());
+    };
+
+    if let Some(rest) =
+        pat_tuple.elems.iter().find(|e| matches!(e, Pat::Rest(_)))
+    {
+        return Err(Error::new_spanned(
+            rest,
+            "rest patterns are not allowed when destructuring a tuple of \
+             values",
+        ));
+    }
+    if pat_tuple.elems.len() != expr_tuple.elems.len() {
+        return Err(Error::new_spanned(
+            pat,
+            format!(
+                "expected a tuple pattern with {} elements, found one with {} \
+                 elements",
+                expr_tuple.elems.len(),
+                pat_tuple.elems.len(),
+            ),
+        ));
+    }
+
+    for (pat, expr) in pat_tuple.elems.iter().zip(expr_tuple.elems.iter()) {
+        split_tuples(pat, expr, leaves)?;
+    }
+    Ok(())
+}
+
+fn destructure_value(
+    crate_path: &Path,
+    pat: &Pat,
+    expr: TokenStream,
+) -> Result<TokenStream, Error> {
+    // See through up to three `Transparent` layers.
+    let peel = quote! {
+        let value = (&#crate_path::__macro::Probe::new(&value))
+            .kind()
+            .apply(value);
+    };
+
+    destructure_unpeeled(
+        crate_path,
+        pat,
+        quote! { {
+            use #crate_path::__macro::{PeelNested as _, PeelValue as _};
+
+            let value = #expr;
+            #peel
+            #peel
+            #peel
+            value
+        } },
+    )
+}
+
+/// Destructures a value without seeing through `Transparent` layers.
+fn destructure_unpeeled(
+    crate_path: &Path,
+    pat: &Pat,
+    expr: TokenStream,
+) -> Result<TokenStream, Error> {
+    let mut test_checks = Vec::new();
+    let test_pat = strip_mut(crate_path, pat, &mut test_checks)?;
+
+    let mut wildcards = Vec::new();
+    let (bindings, exprs) = parse_pat(crate_path, pat, None, &mut wildcards)?;
+    let declare_wildcards = declare_wildcards(&wildcards);
+    let drop_unrestructured = drop_unrestructured(crate_path, pat);
+
+    Ok(quote! {
+        let mut destructurer =
+            #crate_path::__macro::make_destructurer(#expr);
+        #declare_wildcards
+        let #bindings = {
+            #[allow(
+                unused_mut,
+                unused_unsafe,
+                clippy::undocumented_unsafe_blocks,
+                clippy::unused_unit,
+            )]
+            {
+                use #crate_path::__macro::MaybeReference as _;
+
+                let ptr = #crate_path::__macro::destructurer_ptr(
+                    &mut destructurer
+                );
 
-        result.extend(quote! {
-            let mut destructurer = #crate_path::__macro::make_destructurer(
-                #expr
-            );
-            let #bindings = {
                 #[allow(
-                    unused_mut,
-                    unused_unsafe,
-                    clippy::undocumented_unsafe_blocks,
+                    unreachable_code,
+                    unreachable_patterns,
+                    unused_variables,
                 )]
-                {
-                    use #crate_path::__macro::MaybeReference as _;
+                if false {
+                    // SAFETY: This can never be called.
+                    unsafe { ::core::hint::unreachable_unchecked() };
+                    // SAFETY: This can never be called.
+                    let #test_pat = unsafe {
+                        #crate_path::__macro::test_destructurer(
+                            &mut destructurer,
+                        )
+                    };
+                    #(#test_checks)*
+                }
+
+                // If restructuring a field panics, the fields which haven't
+                // been restructured yet are dropped instead of leaked.
+                let guard_ptr = ptr;
+                let guard = #crate_path::__macro::UnwindGuard::new(|| {
+                    #[allow(unused_variables)]
+                    let ptr = guard_ptr;
+                    #[allow(unused_mut, unused_variables)]
+                    let mut index = 0;
+                    #drop_unrestructured
+                });
+                let restructured = #exprs;
+                guard.disarm();
+                restructured
+            }
+        };
+    })
+}
 
-                    let ptr = #crate_path::__macro::destructurer_ptr(
-                        &mut destructurer
+/// The parts of a refutable destructure.
+struct Refutable {
+    /// Tests the pattern against the underlying type.
+    test_pat: Pat,
+    test_checks: Vec<TokenStream>,
+    /// A pattern which binds the pointers to each bound field.
+    ptr_pat: TokenStream,
+    /// An expression which evaluates to `Some` pointers if the pattern
+    /// matches.
+    ptrs: TokenStream,
+    /// Restructures the pointers into the bindings of the pattern.
+    restructure: TokenStream,
+}
+
+fn parse_refutable(crate_path: &Path, pat: &Pat) -> Result<Refutable, Error> {
+    let mut test_checks = Vec::new();
+    let test_pat = strip_mut(crate_path, pat, &mut test_checks)?;
+
+    let mut leaves = Vec::new();
+    let mut wildcards = Vec::new();
+    let (ptr_pat, exprs) =
+        parse_pat(crate_path, pat, Some(&mut leaves), &mut wildcards)?;
+    let declare_wildcards = declare_wildcards(&wildcards);
+    let restructure = leaves.iter().map(|leaf| {
+        let Leaf {
+            attrs,
+            binding,
+            declared,
+            ptr,
+            restructure,
+            rest,
+        } = leaf;
+        if let Some((span, named)) = rest {
+            let drop_rest = rest_call(
+                crate_path,
+                *span,
+                "drop_rest",
+                quote! { #ptr, #named },
+            );
+            return quote! {
+                #(#attrs)*
+                // SAFETY: The pointer is a properly-aligned pointer to a
+                // subfield of the pointer underlying `destructurer`, and only
+                // the fields in `named` have been restructured.
+                unsafe { #drop_rest; }
+            };
+        }
+        let restructure = quote! {
+            // SAFETY: Each pointer is a properly-aligned pointer to a distinct
+            // subfield of the pointer underlying `destructurer`.
+            unsafe { #restructure(&destructurer, #ptr) }
+        };
+        // Attributes may not be applied to assignments, so the assignments to
+        // declared bindings are wrapped in blocks.
+        if *declared {
+            quote! { #(#attrs)* { #binding = #restructure; } }
+        } else {
+            quote! { #(#attrs)* let #binding = #restructure; }
+        }
+    });
+    let drop_unrestructured = leaves.iter().map(|leaf| {
+        let Leaf {
+            attrs, ptr, rest, ..
+        } = leaf;
+        if let Some((span, named)) = rest {
+            let drop_rest = rest_call(
+                crate_path,
+                *span,
+                "drop_unrestructured_rest",
+                quote! { #ptr, #named, &mut index },
+            );
+            return quote! {
+                #(#attrs)*
+                // SAFETY: The pointer is a properly-aligned pointer to a
+                // subfield of the pointer underlying `destructurer`, visited in
+                // the same order that the fields are restructured.
+                unsafe { #drop_rest; }
+            };
+        }
+        quote! {
+            #(#attrs)*
+            // SAFETY: Each pointer is a properly-aligned pointer to a distinct
+            // subfield of the pointer underlying `destructurer`, visited in the
+            // same order that the fields are restructured.
+            unsafe {
+                #crate_path::__macro::drop_unrestructured(
+                    &destructurer,
+                    #ptr,
+                    &mut index,
+                );
+            }
+        }
+    });
+
+    Ok(Refutable {
+        test_pat,
+        test_checks,
+        ptr_pat,
+        ptrs: quote! { {
+            #[allow(
+                unreachable_code,
+                unreachable_patterns,
+                unused_unsafe,
+                clippy::undocumented_unsafe_blocks,
+            )]
+            {
+                use #crate_path::__macro::{
+                    MaybeReference as _,
+                    StableAccess as _,
+                    UnstableAccess as _,
+                };
+
+                #crate_path::__macro::check_discriminant_stable(&destructurer);
+                let access =
+                    (&#crate_path::__macro::access_probe(&destructurer))
+                        .access();
+                let ptr = #crate_path::__macro::destructurer_ptr(
+                    &mut destructurer
+                );
+
+                'munge: { ::core::option::Option::Some(#exprs) }
+            }
+        } },
+        // If restructuring a field panics, the fields which haven't been
+        // restructured yet are dropped instead of leaked.
+        restructure: quote! {
+            #declare_wildcards
+            #[allow(unused_unsafe, clippy::undocumented_unsafe_blocks)]
+            let guard = #crate_path::__macro::UnwindGuard::new(|| {
+                #[allow(unused_mut, unused_variables)]
+                let mut index = 0;
+                #(#drop_unrestructured)*
+            });
+            #(#restructure)*
+            guard.disarm();
+        },
+    })
+}
+
+/// Destructures a value with a refutable pattern, evaluating `else_block` if
+/// the pattern does not match.
+fn destructure_let_else(
+    crate_path: &Path,
+    pat: &Pat,
+    expr: &Expr,
+    else_block: &Block,
+) -> Result<TokenStream, Error> {
+    let Refutable {
+        test_pat,
+        test_checks,
+        ptr_pat,
+        ptrs,
+        restructure,
+    } = parse_refutable(crate_path, pat)?;
+
+    Ok(quote! {
+        let mut destructurer = #crate_path::__macro::make_destructurer(#expr);
+
+        #[allow(unreachable_code, unused_variables)]
+        if false {
+            // SAFETY: This can never be called.
+            unsafe { ::core::hint::unreachable_unchecked() };
+            // SAFETY: This can never be called.
+            let #test_pat = (unsafe {
+                #crate_path::__macro::test_destructurer(&mut destructurer)
+            }) else {
+                ::core::panic!()
+            };
+            #(#test_checks)*
+        }
+
+        let ::core::option::Option::Some(#ptr_pat) = (#ptrs) else #else_block;
+        #restructure
+    })
+}
+
+/// Destructures a value with the first of several refutable patterns that
+/// matches it.
+fn destructure_match(
+    crate_path: &Path,
+    expr: &Expr,
+    arms: &[Arm],
+) -> Result<TokenStream, Error> {
+    let refutables = arms
+        .iter()
+        .map(|arm| parse_refutable(crate_path, &arm.pat))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let test_arms = refutables.iter().map(|r| {
+        let test_pat = &r.test_pat;
+        let test_checks = &r.test_checks;
+        quote! { #test_pat => { #(#test_checks)* } }
+    });
+
+    // Each arm is only tested after all of the arms before it fail to match,
+    // so the pointers for a matching arm are never invalidated by testing
+    // another arm.
+    let mut branches = quote! {
+        ::core::unreachable!("munge match arms are exhaustive")
+    };
+    for (arm, refutable) in arms.iter().zip(refutables.iter()).rev() {
+        let Refutable {
+            ptr_pat,
+            ptrs,
+            restructure,
+            ..
+        } = refutable;
+        let body = &arm.body;
+        branches = quote! {
+            if let ::core::option::Option::Some(#ptr_pat) = (#ptrs) {
+                #restructure
+                #body
+            } else {
+                #branches
+            }
+        };
+    }
+
+    Ok(quote! { {
+        let mut destructurer = #crate_path::__macro::make_destructurer(#expr);
+
+        #[allow(unreachable_code, unused_variables)]
+        if false {
+            // SAFETY: This can never be called.
+            unsafe { ::core::hint::unreachable_unchecked() };
+            // SAFETY: This can never be called.
+            match unsafe {
+                #crate_path::__macro::test_destructurer(&mut destructurer)
+            } {
+                #(#test_arms)*
+            }
+        }
+
+        #branches
+    } })
+}
+
+/// Writes the discriminant of an enum variant into a value and destructures the
+/// fields of the variant's payload.
+fn destructure_emplace(
+    crate_path: &Path,
+    pat: &Pat,
+    expr: &Expr,
+) -> Result<TokenStream, Error> {
+    let (qself, path, fields, rest) = match pat {
+        Pat::Struct(pat_struct) => (
+            &pat_struct.qself,
+            &pat_struct.path,
+            pat_struct
+                .fields
+                .iter()
+                .map(|fp| (fp.member.clone(), &*fp.pat))
+                .collect::<Vec<_>>(),
+            pat_struct.rest.as_ref(),
+        ),
+        Pat::TupleStruct(pat_tuple_struct) => {
+            let elems = &pat_tuple_struct.elems;
+            let rest = elems.iter().position(|e| matches!(e, Pat::Rest(_)));
+            if let Some(i) = rest.filter(|i| i + 1 != elems.len()) {
+                return Err(Error::new_spanned(
+                    &elems[i],
+                    "rest patterns must be last when emplacing a tuple variant",
+                ));
+            }
+            (
+                &pat_tuple_struct.qself,
+                &pat_tuple_struct.path,
+                elems
+                    .iter()
+                    .take(rest.unwrap_or(elems.len()))
+                    .enumerate()
+                    .map(|(i, e)| (Member::Unnamed(Index::from(i)), e))
+                    .collect(),
+                rest.map(|i| match &elems[i] {
+                    Pat::Rest(rest) => rest,
+                    _ => unreachable!(),
+                }),
+            )
+        }
+        Pat::Path(pat_path) => {
+            (&pat_path.qself, &pat_path.path, Vec::new(), None)
+        }
+        _ => {
+            return Err(Error::new_spanned(
+                pat,
+                "expected an enum variant to emplace",
+            ));
+        }
+    };
+    if qself.is_some() || path.segments.len() < 2 {
+        return Err(Error::new_spanned(
+            path,
+            "expected a path to an enum variant, like `Enum::Variant`",
+        ));
+    }
+
+    let mut enum_path = path.clone();
+    let variant = enum_path.segments.pop().unwrap().into_value().ident;
+    enum_path.segments.pop_punct();
+    let emplace_fn =
+        format_ident!("__munge_emplace_{}", variant, span = variant.span(),);
+
+    let mut test_checks = Vec::new();
+    let test_pat = strip_mut(crate_path, pat, &mut test_checks)?;
+
+    let rest_check = rest.map(|rest| make_rest_check(crate_path, rest));
+    let mut wildcards = Vec::new();
+    let (members, (bindings, exprs)) = fields
+        .into_iter()
+        .map(|(member, pat)| {
+            parse_pat(crate_path, pat, None, &mut wildcards)
+                .map(|parsed| (member, parsed))
+        })
+        .collect::<Result<Vec<_>, Error>>()?
+        .into_iter()
+        .unzip::<_, _, Vec<_>, (Vec<_>, Vec<_>)>();
+    let declare_wildcards = declare_wildcards(&wildcards);
+
+    Ok(quote! {
+        let mut destructurer = #crate_path::__macro::make_destructurer(#expr);
+        #declare_wildcards
+        let (#(#bindings,)*) = {
+            #[allow(
+                unused_imports,
+                unused_mut,
+                unused_unsafe,
+                clippy::undocumented_unsafe_blocks,
+                clippy::unused_unit,
+            )]
+            {
+                use #crate_path::__macro::MaybeReference as _;
+
+                #crate_path::__macro::check_emplace(&destructurer);
+                let ptr = #crate_path::__macro::destructurer_ptr(
+                    &mut destructurer
+                );
+
+                #[allow(unreachable_code, unused_variables)]
+                if false {
+                    // SAFETY: This can never be called.
+                    unsafe { ::core::hint::unreachable_unchecked() };
+                    // SAFETY: This can never be called.
+                    let #test_pat = (unsafe {
+                        #crate_path::__macro::test_destructurer(
+                            &mut destructurer,
+                        )
+                    }) else {
+                        ::core::panic!()
+                    };
+                    #(#test_checks)*
+                }
+
+                #rest_check
+
+                // SAFETY: `ptr` is guaranteed to always be non-null,
+                // properly-aligned, and valid for writes.
+                let fields = unsafe { #enum_path::#emplace_fn(ptr) };
+
+                ( #({
+                    let ptr = fields.#members;
+
+                    #exprs
+                },)* )
+            }
+        };
+    })
+}
+
+/// Collects the bindings introduced by an irrefutable pattern.
+///
+/// Identifiers in refutable patterns may name paths instead, so their bindings
+/// must be filtered with [`is_path_ident`].
+struct Bindings<'a>(Vec<Cow<'a, PatIdent>>);
+
+impl Bindings<'_> {
+    /// Returns an expression which evaluates to a tuple of the bindings, or
+    /// nothing if there are no bindings.
+    fn tuple(&self) -> TokenStream {
+        if self.0.is_empty() {
+            TokenStream::new()
+        } else {
+            let idents = self.0.iter().map(|b| &b.ident);
+            quote! { (#(#idents,)*) }
+        }
+    }
+}
+
+impl<'a> Visit<'a> for Bindings<'a> {
+    fn visit_pat_ident(&mut self, pat_ident: &'a PatIdent) {
+        self.0.push(Cow::Borrowed(pat_ident));
+        visit::visit_pat_ident(self, pat_ident);
+    }
+
+    fn visit_expr_macro(&mut self, pat_macro: &'a PatMacro) {
+        // Malformed `raw!` bindings are reported when the pattern is parsed.
+        if is_raw_binding(pat_macro) {
+            if let Ok(pat_ident) = parse_raw_binding(pat_macro) {
+                self.0.push(Cow::Owned(pat_ident));
+            }
+        } else if is_placeholder(pat_macro) {
+            if let Ok(bindings) = parse_placeholder(pat_macro) {
+                self.0.extend(bindings.into_iter().map(Cow::Owned));
+            }
+        }
+    }
+}
+
+/// Removes `mut` from all of the bindings in a pattern.
+struct StripBindingMut;
+
+impl VisitMut for StripBindingMut {
+    fn visit_pat_ident_mut(&mut self, pat_ident: &mut PatIdent) {
+        pat_ident.mutability = None;
+        visit_mut::visit_pat_ident_mut(self, pat_ident);
+    }
+
+    fn visit_expr_macro_mut(&mut self, pat_macro: &mut PatMacro) {
+        if is_raw_binding(pat_macro) {
+            if let Ok(PatIdent { ident, .. }) = parse_raw_binding(pat_macro) {
+                pat_macro.mac.tokens = ident.into_token_stream();
+            }
+        } else if is_placeholder(pat_macro) {
+            if let Ok(bindings) = parse_placeholder(pat_macro) {
+                let idents = bindings.iter().map(|b| &b.ident);
+                pat_macro.mac.tokens = quote! { #(#idents),* };
+            }
+        }
+    }
+}
+
+/// Returns an expression which destructures a value and evaluates to the
+/// bindings of `pat` as a tuple. If the value is inside an `Option` or
+/// `Result`, the tuple is returned in the same container.
+fn destructure_lift(
+    crate_path: &Path,
+    pat: &Pat,
+    expr: &Expr,
+) -> Result<TokenStream, Error> {
+    let mut bindings = Bindings(Vec::new());
+    bindings.visit_pat(pat);
+    let tuple = bindings.tuple();
+
+    let mut value_pat = pat.clone();
+    StripBindingMut.visit_pat_mut(&mut value_pat);
+    let value = destructure_value(crate_path, &value_pat, quote! { value })?;
+
+    Ok(quote! {
+        #crate_path::__macro::lift(#expr, |value| {
+            #value
+            #tuple
+        })
+    })
+}
+
+fn destructure_columns(
+    crate_path: &Path,
+    pat: &Pat,
+    len: &Expr,
+    expr: &Expr,
+) -> Result<TokenStream, Error> {
+    let mut bindings = Bindings(Vec::new());
+    bindings.visit_pat(pat);
+    let row_tuple = bindings.tuple();
+    let bindings = bindings.0;
+
+    let mut row_pat = pat.clone();
+    StripBindingMut.visit_pat_mut(&mut row_pat);
+    let row = destructure_value(crate_path, &row_pat, quote! { row })?;
+
+    let column_bindings = bindings.iter().map(|b| {
+        let mutability = &b.mutability;
+        let ident = &b.ident;
+        quote! { #mutability #ident }
+    });
+    let indices = (0..bindings.len()).map(Index::from);
+
+    // Const arguments can't be inferred with `_` before Rust 1.89, so the
+    // length is left out of the turbofish entirely when it's inferred.
+    let into_rows = match len {
+        Expr::Infer(_) => quote! { #crate_path::__macro::into_rows },
+        len => quote! { #crate_path::__macro::into_rows::<_, { #len }, _> },
+    };
+
+    Ok(quote! {
+        let (#(#column_bindings,)*) = {
+            let rows = #into_rows(#expr)
+                .map(|row| {
+                    #row
+                    #row_tuple
+                });
+            let rows = ::core::mem::ManuallyDrop::new(rows);
+            (#(
+                // SAFETY: Each field of each row is read exactly once, and the
+                // rows are never dropped.
+                unsafe {
+                    #crate_path::__macro::take_column(&rows, |row| {
+                        ::core::ptr::addr_of!(row.#indices)
+                    })
+                },
+            )*)
+        };
+    })
+}
+
+/// Destructures borrowed `MaybeUninit`s and `ManuallyDrop`s in a constant
+/// context.
+///
+/// Constant contexts can't call the trait methods that regular destructures
+/// use, so fields are projected and restructured with inherent `const fn`s
+/// instead. The regular destructure is still generated inside of a closure
+/// which is never called, so patterns are checked exactly like they are for
+/// regular destructures.
+fn destructure_const(input: Input) -> Result<TokenStream, Error> {
+    let crate_path = &input.crate_path;
+    let Body::Destructures(destructures) = &input.body else {
+        return Err(Error::new(
+            Span::call_site(),
+            "`munge_const!` only supports `let` destructures",
+        ));
+    };
+
+    let value = Ident::new("value", Span::mixed_site());
+    let destructurer = Ident::new("destructurer", Span::mixed_site());
+
+    let mut errors = Vec::new();
+    let mut result = TokenStream::new();
+    for destructure in destructures.iter() {
+        let unsupported = destructure
+            .emplace
+            .as_ref()
+            .map(ToTokens::to_token_stream)
+            .or_else(|| {
+                let columns = destructure.columns.as_ref();
+                columns.map(|_| destructure.pat.to_token_stream())
+            })
+            .or_else(|| {
+                let else_block = destructure.else_block.as_ref();
+                else_block.map(|(else_token, _)| else_token.to_token_stream())
+            })
+            .or_else(|| {
+                let lift = destructure.lift.as_ref();
+                lift.map(|lift| lift.pat.to_token_stream())
+            });
+        if let Some(tokens) = unsupported {
+            errors.push(Error::new_spanned(
+                tokens,
+                "`munge_const!` may not emplace enum variants, destructure \
+                 columns, match refutable patterns, or evaluate to its \
+                 bindings",
+            ));
+            continue;
+        }
+
+        let mut pat = destructure.pat.clone();
+        recover_pat(&mut pat, false, &mut errors);
+        let expr = &destructure.expr;
+
+        let destructured = destructure_unpeeled(
+            crate_path,
+            &pat,
+            quote! {
+                #crate_path::__macro::test_const_destructurer(&#destructurer)
+            },
+        )
+        .and_then(|check| {
+            let (bindings, exprs) = parse_const_pat(crate_path, &pat)?;
+            Ok(quote! {
+                let #value: &mut _ = #expr;
+                let #destructurer =
+                    #crate_path::__macro::ConstDestructurer::new(
+                        ::core::ptr::addr_of_mut!(*#value),
                     );
+                #[allow(unused_mut, unused_variables)]
+                let _ = || { #check };
+                let #bindings = {
+                    #[allow(
+                        unused_mut,
+                        unused_unsafe,
+                        clippy::undocumented_unsafe_blocks,
+                        clippy::unused_unit,
+                    )]
+                    {
+                        /// # Safety
+                        ///
+                        /// `ptr` must be a properly-aligned pointer to a
+                        /// subfield of the pointer underlying `value`.
+                        const unsafe fn restructure<'a, T, U>(
+                            _: &&'a mut T,
+                            ptr: *mut U,
+                        ) -> &'a mut U
+                        where
+                            T: ?Sized,
+                            U: ?Sized,
+                        {
+                            // SAFETY: The caller has guaranteed that `ptr`
+                            // points to a subfield of the pointer underlying
+                            // `value`, which is borrowed for `'a`.
+                            unsafe { &mut *ptr }
+                        }
 
-                    #[allow(unreachable_code, unused_variables)]
-                    if false {
-                        // SAFETY: This can never be called.
-                        unsafe { ::core::hint::unreachable_unchecked() };
-                        // SAFETY: This can never be called.
-                        let #test_pat = unsafe {
-                            #crate_path::__macro::test_destructurer(
-                                &mut destructurer,
-                            )
-                        };
+                        let ptr = #destructurer.underlying();
+
+                        #exprs
                     }
+                };
+            })
+        })
+        .and_then(|d| carry_stmt_attrs(&destructure.attrs, d));
+        match destructured {
+            Ok(destructured) => result.extend(destructured),
+            Err(error) => errors.push(error),
+        }
+    }
+    result.extend(errors.iter().map(Error::to_compile_error));
+    Ok(result)
+}
 
-                    #exprs
+/// Parses a destructuring pattern for `munge_const!`, returning the bindings
+/// it introduces and an expression which evaluates to the restructured values
+/// for those bindings.
+///
+/// Unlike `parse_pat`, this doesn't test each part of the pattern since the
+/// pattern is already checked by the regular destructure.
+fn parse_const_pat(
+    crate_path: &Path,
+    pat: &Pat,
+) -> Result<(TokenStream, TokenStream), Error> {
+    let value = Ident::new("value", Span::mixed_site());
+    let destructurer = Ident::new("destructurer", Span::mixed_site());
+    let restructure = quote! {
+        // SAFETY: `ptr` is a properly-aligned pointer to a subfield of the
+        // pointer underlying `value`.
+        unsafe { restructure(&#value, #destructurer.restructure(ptr)) }
+    };
+    let project = |member: TokenStream, expr: TokenStream| {
+        quote! { {
+            // SAFETY: `ptr` is guaranteed to always be non-null,
+            // properly-aligned, and valid for reads.
+            let ptr = unsafe { ::core::ptr::addr_of_mut!((*ptr)#member) };
+
+            #expr
+        } }
+    };
+
+    Ok(match pat {
+        Pat::Path(_) | Pat::Wild(_) => (quote! { _ }, quote! { () }),
+        Pat::Ident(pat_ident) => {
+            if let Some(by_ref) = &pat_ident.by_ref {
+                return Err(Error::new_spanned(
+                    by_ref,
+                    "`ref` bindings can't be used in `munge_const!`",
+                ));
+            }
+            let mutability = &pat_ident.mutability;
+            let ident = &pat_ident.ident;
+            let binding = quote! { #mutability #ident };
+            let Some((_, subpat)) = &pat_ident.subpat else {
+                return Ok((binding, restructure));
+            };
+            let (sub_binding, sub_expr) = parse_const_pat(crate_path, subpat)?;
+            (
+                quote! { (#binding, #sub_binding) },
+                quote! { (#restructure, #sub_expr) },
+            )
+        }
+        Pat::Macro(pat_macro) if is_placeholder(pat_macro) => {
+            let bindings = parse_placeholder(pat_macro)?;
+            let exprs = bindings.iter().map(|_| &restructure);
+            (quote! { (#(#bindings,)*) }, quote! { (#(#exprs,)*) })
+        }
+        Pat::Macro(pat_macro) if is_raw_binding(pat_macro) => {
+            return Err(Error::new_spanned(
+                pat_macro,
+                "`raw!` bindings can't be used in `munge_const!`",
+            ));
+        }
+        Pat::Paren(pat_paren) => parse_const_pat(crate_path, &pat_paren.pat)?,
+        Pat::Type(pat_type) => {
+            let (bindings, expr) = parse_const_pat(crate_path, &pat_type.pat)?;
+            let ty = &pat_type.ty;
+            (
+                bindings,
+                quote! { {
+                    let ptr: *mut #ty = ptr;
+
+                    #expr
+                } },
+            )
+        }
+        Pat::Tuple(PatTuple { elems, .. })
+        | Pat::TupleStruct(PatTupleStruct { elems, .. }) => {
+            check_tuple_rest(elems)?;
+            let (bindings, exprs) = elems
+                .iter()
+                .filter(|e| !matches!(e, Pat::Rest(_)))
+                .enumerate()
+                .map(|(i, e)| {
+                    let (binding, expr) = parse_const_pat(crate_path, e)?;
+                    let index = Index::from(i);
+                    Ok((binding, project(quote! { .#index }, expr)))
+                })
+                .collect::<Result<Vec<_>, Error>>()?
+                .into_iter()
+                .unzip::<_, _, Vec<_>, Vec<_>>();
+            (quote! { (#(#bindings,)*) }, quote! { (#(#exprs,)*) })
+        }
+        Pat::Slice(pat_slice) => {
+            let rest_position = pat_slice
+                .elems
+                .iter()
+                .position(|e| matches!(e, Pat::Rest(_)));
+            let parsed = pat_slice
+                .elems
+                .iter()
+                .filter(|e| !matches!(e, Pat::Rest(_)))
+                .map(|e| parse_const_pat(crate_path, e))
+                .collect::<Result<Vec<_>, Error>>()?;
+
+            // Elements after the rest pattern are indexed from the end of the
+            // array. The regular destructure checks that the array is long
+            // enough to contain every index.
+            let len = parsed.len();
+            let front = rest_position.unwrap_or(len);
+            let (bindings, exprs) = parsed
+                .into_iter()
+                .enumerate()
+                .map(|(i, (binding, expr))| {
+                    let index = if i < front {
+                        quote! { #i }
+                    } else {
+                        let from_end = len - i;
+                        quote! { len - #from_end }
+                    };
+                    (binding, project(quote! { [#index] }, expr))
+                })
+                .unzip::<_, _, Vec<_>, Vec<_>>();
+
+            (
+                quote! { (#(#bindings,)*) },
+                quote! { {
+                    #[allow(unused_variables)]
+                    let len = #crate_path::__macro::const_array_len(ptr);
+
+                    (#(#exprs,)*)
+                } },
+            )
+        }
+        Pat::Struct(pat_struct) => {
+            let (bindings, exprs) = pat_struct
+                .fields
+                .iter()
+                .map(|fp| {
+                    let (binding, expr) = parse_const_pat(crate_path, &fp.pat)?;
+                    let member = &fp.member;
+                    let expr = project(quote! { .#member }, expr);
+                    Ok(carry_attrs(crate_path, &fp.attrs, (binding, expr)))
+                })
+                .collect::<Result<Vec<_>, Error>>()?
+                .into_iter()
+                .unzip::<_, _, Vec<_>, Vec<_>>();
+            (quote! { (#(#bindings,)*) }, quote! { (#(#exprs,)*) })
+        }
+        _ => return Err(unsupported_pat(pat)),
+    })
+}
+
+/// Initializes a `&mut MaybeUninit` in place from a struct literal, evaluating
+/// to a mutable reference to the initialized value.
+///
+/// The struct literal is converted into a pattern which binds each field it
+/// initializes, and that pattern is destructured like any other. Struct, tuple,
+/// and array patterns must name every field, so destructuring the pattern
+/// proves that every field is written. Structs with `..zeroed` are zero-filled
+/// before any of their fields are written.
+fn init(input: InitInput) -> Result<TokenStream, Error> {
+    let crate_path = &input.crate_path;
+    let uninit = Ident::new("uninit", Span::mixed_site());
+
+    let mut leaves = Vec::new();
+    let pat = init_pat(&input.init, &mut leaves)?;
+    let mut zeroed = Vec::new();
+    let zero_fill = match zeroed_pat(&input.init, &mut zeroed)? {
+        Some(zeroed_pat) => {
+            let destructure = destructure_value(
+                crate_path,
+                &zeroed_pat,
+                quote! { &mut *#uninit },
+            )?;
+            // The binding is wrapped in a block so that errors point to
+            // `..zeroed` instead of the whole macro.
+            let zero_fills = zeroed.iter().map(|(ident, span)| {
+                quote_spanned! { *span =>
+                    #[allow(unused_braces)]
+                    #crate_path::__macro::zero_fill({ #ident });
                 }
+            });
+            quote! { {
+                #destructure
+                #(#zero_fills)*
+            } }
+        }
+        None => TokenStream::new(),
+    };
+    let destructure =
+        destructure_value(crate_path, &pat, quote! { &mut *#uninit })?;
+
+    let fields = (0..leaves.len()).map(init_field).collect::<Vec<_>>();
+    let values = (0..leaves.len())
+        .map(|i| Ident::new(&format!("value_{i}"), Span::mixed_site()))
+        .collect::<Vec<_>>();
+    let expr = &input.expr;
+
+    // Values are evaluated before any fields are written, so they can't
+    // observe a partially-initialized value.
+    Ok(quote! { {
+        let #uninit: &mut ::core::mem::MaybeUninit<_> = #expr;
+        let (#(#values,)*) = (#(#leaves,)*);
+        #zero_fill
+        {
+            #destructure
+            #(#fields.write(#values);)*
+        }
+        #[allow(clippy::undocumented_unsafe_blocks)]
+        {
+            // SAFETY: Every field of the value has been written or
+            // zero-filled.
+            unsafe { #uninit.assume_init_mut() }
+        }
+    } })
+}
+
+/// Finishes initializing the storage of a `Finish` from tokens in the shape of
+/// a struct literal, evaluating to a mutable reference to the initialized
+/// value.
+///
+/// The struct literal is converted into a pattern like it is for `init!`, and
+/// that pattern is destructured from a never-used `Uninit` with the same brand.
+/// Because the pattern must name every field, the tokens must partition the
+/// value the same way that its slots did, and each slot produces at most one
+/// token. So matching every token to a slot of the same type proves that every
+/// slot was written.
+fn finish(input: InitInput) -> Result<TokenStream, Error> {
+    let crate_path = &input.crate_path;
+    let finish = Ident::new("finish", Span::mixed_site());
+
+    check_no_base(&input.init)?;
+    let mut leaves = Vec::new();
+    let pat = init_pat(&input.init, &mut leaves)?;
+    let destructure = destructure_value(
+        crate_path,
+        &pat,
+        quote! { #crate_path::__macro::test_uninit(&#finish) },
+    )?;
+
+    let fields = (0..leaves.len()).map(init_field).collect::<Vec<_>>();
+    // The tokens are spanned to their expressions so that errors point to
+    // each token instead of the whole macro. They are bound before any user
+    // code can refer to them, so they don't need to be hygienic.
+    let tokens = leaves
+        .iter()
+        .enumerate()
+        .map(|(i, leaf)| Ident::new(&format!("token_{i}"), leaf.span()))
+        .collect::<Vec<_>>();
+    let checks = fields.iter().zip(&tokens).map(|(field, token)| {
+        let token = quote_spanned! { token.span() => &#token };
+        quote! { #crate_path::__macro::check_written(#field, #token); }
+    });
+    let expr = &input.expr;
+
+    Ok(quote! { {
+        let #finish = #expr;
+        let (#(#tokens,)*) = (#(#leaves,)*);
+        #[allow(unreachable_code, unused_variables)]
+        if false {
+            #destructure
+            #(#checks)*
+        }
+        // Every field has been written, so the tokens no longer need to drop
+        // their fields.
+        ::core::mem::forget((#(#tokens,)*));
+        #[allow(clippy::undocumented_unsafe_blocks)]
+        {
+            // SAFETY: Each slot produces at most one token, and there is a
+            // token for every field of the value.
+            unsafe { #crate_path::__macro::finish_unchecked(#finish) }
+        }
+    } })
+}
+
+/// Checks that no struct literal in `finish!` has a base, since every field
+/// must have a token.
+fn check_no_base(expr: &Expr) -> Result<(), Error> {
+    match expr {
+        Expr::Struct(expr_struct) => {
+            if let Some(dot2_token) = &expr_struct.dot2_token {
+                let rest = &expr_struct.rest;
+                return Err(Error::new_spanned(
+                    quote! { #dot2_token #rest },
+                    "`finish!` requires a token for every field",
+                ));
+            }
+            expr_struct
+                .fields
+                .iter()
+                .try_for_each(|fv| check_no_base(&fv.expr))
+        }
+        Expr::Tuple(expr_tuple) => {
+            expr_tuple.elems.iter().try_for_each(check_no_base)
+        }
+        Expr::Array(expr_array) => {
+            expr_array.elems.iter().try_for_each(check_no_base)
+        }
+        Expr::Paren(expr_paren) => check_no_base(&expr_paren.expr),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the name of the binding for the `i`th field written by `init!`.
+fn init_field(i: usize) -> Ident {
+    Ident::new(&format!("field_{i}"), Span::mixed_site())
+}
+
+/// Checks that the base of a struct literal in `init!` is `zeroed`, returning
+/// whether the struct has a base.
+fn check_zeroed(expr_struct: &ExprStruct) -> Result<bool, Error> {
+    let Some(dot2_token) = &expr_struct.dot2_token else {
+        return Ok(false);
+    };
+    let rest = &expr_struct.rest;
+    match rest.as_deref() {
+        Some(Expr::Path(expr_path)) if expr_path.path.is_ident("zeroed") => {
+            Ok(true)
+        }
+        _ => Err(Error::new_spanned(
+            quote! { #dot2_token #rest },
+            "only `..zeroed` may fill the remaining fields of a struct in \
+             `init!`",
+        )),
+    }
+}
+
+/// Converts a struct literal for `init!` into a pattern which binds each of
+/// the fields it writes, pushing the value for each field to `leaves`.
+///
+/// Struct, tuple, and array expressions are converted into the corresponding
+/// patterns. Any other expression is a value which is written to its field.
+fn init_pat<'a>(
+    expr: &'a Expr,
+    leaves: &mut Vec<&'a Expr>,
+) -> Result<Pat, Error> {
+    Ok(match expr {
+        Expr::Struct(expr_struct) => {
+            let zeroed = check_zeroed(expr_struct)?;
+            let fields = expr_struct
+                .fields
+                .iter()
+                .map(|fv| {
+                    Ok(FieldPat {
+                        attrs: fv.attrs.clone(),
+                        member: fv.member.clone(),
+                        colon_token: Some(Default::default()),
+                        pat: Box::new(init_pat(&fv.expr, leaves)?),
+                    })
+                })
+                .collect::<Result<_, Error>>()?;
+            Pat::Struct(PatStruct {
+                attrs: Vec::new(),
+                qself: expr_struct.qself.clone(),
+                path: expr_struct.path.clone(),
+                brace_token: expr_struct.brace_token,
+                fields,
+                rest: zeroed.then(|| PatRest {
+                    attrs: Vec::new(),
+                    dot2_token: Default::default(),
+                }),
+            })
+        }
+        Expr::Tuple(expr_tuple) => Pat::Tuple(PatTuple {
+            attrs: Vec::new(),
+            paren_token: expr_tuple.paren_token,
+            elems: expr_tuple
+                .elems
+                .iter()
+                .map(|e| init_pat(e, leaves))
+                .collect::<Result<_, Error>>()?,
+        }),
+        Expr::Array(expr_array) => Pat::Slice(PatSlice {
+            attrs: Vec::new(),
+            bracket_token: expr_array.bracket_token,
+            elems: expr_array
+                .elems
+                .iter()
+                .map(|e| init_pat(e, leaves))
+                .collect::<Result<_, Error>>()?,
+        }),
+        Expr::Paren(expr_paren) => init_pat(&expr_paren.expr, leaves)?,
+        _ => {
+            let ident = init_field(leaves.len());
+            leaves.push(expr);
+            parse_quote! { #ident }
+        }
+    })
+}
+
+/// Converts a struct literal for `init!` into a pattern which binds each of the
+/// outermost structs filled with `..zeroed`, or returns `None` if it doesn't
+/// zero-fill any fields. The names of the bindings and the spans of their
+/// `..zeroed` are pushed to `zeroed`.
+fn zeroed_pat(
+    expr: &Expr,
+    zeroed: &mut Vec<(Ident, Span)>,
+) -> Result<Option<Pat>, Error> {
+    let mut elems = |exprs: &mut dyn Iterator<Item = &Expr>| {
+        let mut any = false;
+        let pats = exprs
+            .map(|e| {
+                let pat = zeroed_pat(e, zeroed)?;
+                any |= pat.is_some();
+                Ok(pat.unwrap_or_else(|| parse_quote! { _ }))
+            })
+            .collect::<Result<Punctuated<Pat, Token![,]>, Error>>()?;
+        Ok::<_, Error>(any.then_some(pats))
+    };
+
+    Ok(match expr {
+        Expr::Struct(expr_struct) if check_zeroed(expr_struct)? => {
+            let dot2_token = &expr_struct.dot2_token;
+            let rest = &expr_struct.rest;
+            let span = quote! { #dot2_token #rest }.span();
+            let ident = Ident::new(
+                &format!("zeroed_{}", zeroed.len()),
+                Span::mixed_site(),
+            );
+            zeroed.push((ident.clone(), span));
+            Some(parse_quote! { #ident })
+        }
+        Expr::Struct(expr_struct) => {
+            let Some(pats) =
+                elems(&mut expr_struct.fields.iter().map(|fv| &fv.expr))?
+            else {
+                return Ok(None);
             };
+            let fields = expr_struct
+                .fields
+                .iter()
+                .zip(pats)
+                .map(|(fv, pat)| FieldPat {
+                    attrs: fv.attrs.clone(),
+                    member: fv.member.clone(),
+                    colon_token: Some(Default::default()),
+                    pat: Box::new(pat),
+                })
+                .collect();
+            Some(Pat::Struct(PatStruct {
+                attrs: Vec::new(),
+                qself: expr_struct.qself.clone(),
+                path: expr_struct.path.clone(),
+                brace_token: expr_struct.brace_token,
+                fields,
+                rest: None,
+            }))
+        }
+        Expr::Tuple(expr_tuple) => {
+            elems(&mut expr_tuple.elems.iter())?.map(|elems| {
+                Pat::Tuple(PatTuple {
+                    attrs: Vec::new(),
+                    paren_token: expr_tuple.paren_token,
+                    elems,
+                })
+            })
+        }
+        Expr::Array(expr_array) => {
+            elems(&mut expr_array.elems.iter())?.map(|elems| {
+                Pat::Slice(PatSlice {
+                    attrs: Vec::new(),
+                    bracket_token: expr_array.bracket_token,
+                    elems,
+                })
+            })
+        }
+        Expr::Paren(expr_paren) => zeroed_pat(&expr_paren.expr, zeroed)?,
+        _ => None,
+    })
+}
+
+/// Replaces a parameter pattern with a plain binding and returns the munge
+/// destructure for the original pattern.
+fn destructure_param(
+    crate_path: &Path,
+    index: usize,
+    pat: &mut Pat,
+) -> Result<TokenStream, Error> {
+    let param = Ident::new(&format!("param_{index}"), Span::mixed_site());
+    let original = core::mem::replace(
+        pat,
+        Pat::Ident(PatIdent {
+            attrs: Vec::new(),
+            by_ref: None,
+            mutability: None,
+            ident: param.clone(),
+            subpat: None,
+        }),
+    );
+    destructure_value(crate_path, &original, param.into_token_stream())
+}
+
+/// Removes the `#[munge]` attribute from a list of attributes, returning
+/// whether it was present.
+fn take_munge_attr(attrs: &mut Vec<Attribute>) -> bool {
+    let len = attrs.len();
+    attrs.retain(|attr| !attr.path().is_ident("munge"));
+    attrs.len() != len
+}
+
+/// Destructures closure parameters marked with `#[munge]`, like the parameters
+/// of the function.
+struct Closures<'a> {
+    crate_path: &'a Path,
+    result: Result<(), Error>,
+}
+
+impl VisitMut for Closures<'_> {
+    fn visit_expr_closure_mut(&mut self, closure: &mut ExprClosure) {
+        visit_mut::visit_expr_closure_mut(self, closure);
+
+        let mut destructures = TokenStream::new();
+        for (i, input) in closure.inputs.iter_mut().enumerate() {
+            let marked = match input {
+                Pat::Type(pat_type) => take_munge_attr(&mut pat_type.attrs),
+                _ => false,
+            };
+            if !marked {
+                continue;
+            }
+
+            let Pat::Type(pat_type) = input else {
+                unreachable!();
+            };
+            match destructure_param(self.crate_path, i, &mut pat_type.pat) {
+                Ok(destructure) => destructures.extend(destructure),
+                Err(e) => match &mut self.result {
+                    Ok(()) => self.result = Err(e),
+                    Err(error) => error.combine(e),
+                },
+            }
+        }
+
+        if !destructures.is_empty() {
+            let body = &closure.body;
+            *closure.body = parse_quote! { {
+                #destructures
+                #body
+            } };
+        }
+    }
+}
+
+fn destructure_params(
+    attr: ParamsAttr,
+    mut item: ItemFn,
+) -> Result<TokenStream, Error> {
+    let crate_path = &attr.crate_path;
+
+    let mut destructures = TokenStream::new();
+    for (i, input) in item.sig.inputs.iter_mut().enumerate() {
+        let FnArg::Typed(pat_type) = input else {
+            continue;
+        };
+        if take_munge_attr(&mut pat_type.attrs) {
+            destructures.extend(destructure_param(
+                crate_path,
+                i,
+                &mut pat_type.pat,
+            )?);
+        }
+    }
+
+    let mut closures = Closures {
+        crate_path,
+        result: Ok(()),
+    };
+    closures.visit_block_mut(&mut item.block);
+    closures.result?;
+
+    let stmts = &item.block.stmts;
+    *item.block = parse_quote! { {
+        #destructures
+        #(#stmts)*
+    } };
+
+    Ok(item.into_token_stream())
+}
+
+/// The `#[munge(crate = path)]` attribute of a derive.
+fn derive_crate_path(attrs: &[Attribute]) -> Result<Path, Error> {
+    let mut crate_path = parse_quote! { ::munge };
+    for attr in attrs.iter().filter(|a| a.path().is_ident("munge")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                crate_path = meta.value()?.parse::<Path>()?;
+                Ok(())
+            } else {
+                Err(meta.error("unrecognized munge attribute"))
+            }
+        })?;
+    }
+    Ok(crate_path)
+}
+
+fn derive_drop_rest_impl(item: ItemStruct) -> Result<TokenStream, Error> {
+    let crate_path = derive_crate_path(&item.attrs)?;
+
+    // The fields of packed structs may not be aligned, so they can't be
+    // restructured.
+    for attr in item.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("packed") {
+                return Err(Error::new_spanned(
+                    attr,
+                    "`DropRest` may not be derived for packed structs",
+                ));
+            }
+            if meta.input.peek(syn::token::Paren) {
+                // Skip the arguments of `align(..)`.
+                let _ = meta.input.parse::<TokenStream>()?;
+            }
+            Ok(())
+        })?;
+    }
+
+    let ident = &item.ident;
+    let restructurer = format_ident!("__MungeRestructurer");
+    let mut generics = item.generics.clone();
+    generics.params.push(parse_quote! { #restructurer });
+    let where_clause = generics.make_where_clause();
+    for field in &item.fields {
+        let ty = &field.ty;
+        where_clause.predicates.push(parse_quote! {
+            #restructurer: #crate_path::Restructure<#ty>
         });
     }
-    Ok(result)
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = item.generics.split_for_impl();
+
+    let len = item.fields.len();
+    let drops = item.fields.iter().enumerate().map(|(i, field)| {
+        let (member, name) = match &field.ident {
+            Some(ident) => (quote! { #ident }, ident.unraw().to_string()),
+            None => {
+                let index = Index::from(i);
+                (quote! { #index }, i.to_string())
+            }
+        };
+        quote! {
+            #crate_path::__macro::drop_field(
+                restructurer,
+                ::core::ptr::addr_of_mut!((*ptr).#member),
+                named,
+                #name,
+                #i,
+                #len,
+            );
+        }
+    });
+
+    Ok(quote! {
+        // SAFETY: `drop_rest` restructures each field which is not in `named`
+        // exactly once with `restructurer` and drops it, and does not access
+        // the fields in `named`.
+        unsafe impl #impl_generics #crate_path::DropRest<#restructurer>
+            for #ident #ty_generics #where_clause
+        {
+            // Structs without fields don't drop anything.
+            #[allow(unused_unsafe, unused_variables)]
+            unsafe fn drop_rest(
+                ptr: *mut Self,
+                restructurer: &#restructurer,
+                named: #crate_path::__macro::Named<'_>,
+            ) {
+                // SAFETY: The caller has guaranteed that `ptr` is a
+                // properly-aligned pointer to a subfield of the pointer
+                // underlying `restructurer`, and that the fields which are not
+                // in `named` have not been restructured.
+                unsafe {
+                    #(#drops)*
+                }
+            }
+        }
+    })
+}
+
+fn derive_emplace_impl(item: ItemEnum) -> Result<TokenStream, Error> {
+    let crate_path = derive_crate_path(&item.attrs)?;
+
+    // Find the primitive representation of the enum and whether it is also
+    // `repr(C)`.
+    let mut primitive = None;
+    let mut is_c = false;
+    for attr in item.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            const PRIMITIVES: [&str; 12] = [
+                "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32",
+                "i64", "i128", "isize",
+            ];
+            if meta.path.is_ident("C") {
+                is_c = true;
+            } else if PRIMITIVES.iter().any(|p| meta.path.is_ident(p)) {
+                primitive = meta.path.get_ident().cloned();
+            } else if meta.input.peek(syn::token::Paren) {
+                // Skip the arguments of `align(..)` and `packed(..)`.
+                let _ = meta.input.parse::<TokenStream>()?;
+            }
+            Ok(())
+        })?;
+    }
+    let Some(primitive) = primitive else {
+        return Err(Error::new_spanned(
+            &item.ident,
+            "`Emplace` may only be derived for enums with a primitive \
+             representation like `#[repr(u8)]` or `#[repr(C, u8)]`",
+        ));
+    };
+
+    let ident = &item.ident;
+    let vis = &item.vis;
+    let (impl_generics, ty_generics, where_clause) =
+        item.generics.split_for_impl();
+    let generics = &item.generics;
+    let phantom = quote! { ::core::marker::PhantomData<#ident #ty_generics> };
+
+    let mut discriminant = quote! { 0 };
+    let mut items = TokenStream::new();
+    let mut fns = TokenStream::new();
+    let mut payloads = Vec::new();
+    for (i, variant) in item.variants.iter().enumerate() {
+        let name = &variant.ident;
+        if let Some((_, expr)) = &variant.discriminant {
+            discriminant = quote! { #expr };
+        } else if i != 0 {
+            discriminant = quote! { (#discriminant) + 1 };
+        }
+
+        let tys = variant.fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+
+        // `repr(C, prim)` enums are laid out as a `repr(C)` struct of the tag
+        // and a `repr(C)` union of the payloads of each variant. Enums that
+        // are only `repr(prim)` are laid out as a `repr(C)` union of
+        // `repr(C)` structs which each start with the tag.
+        let payload_ty = format_ident!("Payload_{}", name);
+        let (payload_def, payload_ptr, first_field) = if is_c {
+            payloads.push((name, payload_ty.clone()));
+            (
+                quote! {
+                    #[repr(C)]
+                    pub struct #payload_ty #generics (
+                        #(#tys,)*
+                        #phantom,
+                    ) #where_clause;
+                },
+                quote! {
+                    let layout = ptr.cast::<Layout #ty_generics>();
+                    ::core::ptr::addr_of_mut!((*layout).0)
+                        .write(#discriminant);
+                    let payload = ::core::ptr::addr_of_mut!((*layout).1)
+                        .cast::<#payload_ty #ty_generics>();
+                },
+                0,
+            )
+        } else {
+            (
+                quote! {
+                    #[repr(C)]
+                    pub struct #payload_ty #generics (
+                        #primitive,
+                        #(#tys,)*
+                        #phantom,
+                    ) #where_clause;
+                },
+                quote! {
+                    let payload = ptr.cast::<#payload_ty #ty_generics>();
+                    ::core::ptr::addr_of_mut!((*payload).0)
+                        .write(#discriminant);
+                },
+                1,
+            )
+        };
+        items.extend(payload_def);
+
+        let indices = (first_field..first_field + tys.len()).map(Index::from);
+        let fields_ty = format_ident!("Fields_{}", name);
+        let emplace_fn = format_ident!("__munge_emplace_{}", name);
+        let (fields_def, make_fields) = match &variant.fields {
+            syn::Fields::Named(named) => {
+                let names = named.named.iter().map(|f| &f.ident);
+                let names2 = names.clone();
+                (
+                    quote! {
+                        pub struct #fields_ty #generics #where_clause {
+                            #(pub #names: *mut #tys,)*
+                            pub __munge_phantom: #phantom,
+                        }
+                    },
+                    quote! {
+                        #fields_ty {
+                            #(#names2: ::core::ptr::addr_of_mut!(
+                                (*payload).#indices
+                            ),)*
+                            __munge_phantom: ::core::marker::PhantomData,
+                        }
+                    },
+                )
+            }
+            _ => (
+                quote! {
+                    pub struct #fields_ty #generics (
+                        #(pub *mut #tys,)*
+                        pub #phantom,
+                    ) #where_clause;
+                },
+                quote! {
+                    #fields_ty(
+                        #(::core::ptr::addr_of_mut!((*payload).#indices),)*
+                        ::core::marker::PhantomData,
+                    )
+                },
+            ),
+        };
+        items.extend(fields_def);
+
+        fns.extend(quote! {
+            /// # Safety
+            ///
+            /// `ptr` must be non-null, properly aligned, and valid for writes.
+            #[doc(hidden)]
+            #vis unsafe fn #emplace_fn(
+                ptr: *mut Self,
+            ) -> #fields_ty #ty_generics {
+                // SAFETY: The caller has guaranteed that `ptr` is non-null,
+                // properly aligned, and valid for writes. The enum has a
+                // primitive representation, so its layout matches the layout
+                // of the payload type.
+                unsafe {
+                    #payload_ptr
+                    #make_fields
+                }
+            }
+        });
+    }
+
+    if is_c {
+        let (names, tys) = payloads.into_iter().unzip::<_, _, Vec<_>, Vec<_>>();
+        items.extend(quote! {
+            #[repr(C)]
+            pub union Payloads #generics #where_clause {
+                #(#names: ::core::mem::ManuallyDrop<#tys #ty_generics>,)*
+            }
+
+            #[repr(C)]
+            pub struct Layout #generics (
+                #primitive,
+                Payloads #ty_generics,
+            ) #where_clause;
+        });
+    }
+
+    Ok(quote! {
+        #[allow(dead_code, non_camel_case_types, non_snake_case)]
+        const _: () = {
+            #items
+
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #fns
+            }
+
+            // SAFETY: The enum has a primitive representation, and each
+            // emplace function writes the discriminant of its variant.
+            unsafe impl #impl_generics #crate_path::Emplace
+                for #ident #ty_generics #where_clause
+            {
+            }
+        };
+    })
 }